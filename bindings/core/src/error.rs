@@ -30,6 +30,21 @@ pub enum Error {
     /// Unpack errors.
     #[error("{0}")]
     Unpack(#[from] packable::error::UnpackError<iota_sdk::types::block::Error, UnexpectedEOF>),
+    /// Invalid metadata.
+    #[error("invalid metadata: {0}")]
+    InvalidMetadata(String),
+    /// Metadata doesn't follow the expected standard.
+    #[error("invalid metadata standard: expected {expected}, found {found}")]
+    InvalidMetadataStandard { expected: &'static str, found: String },
+    /// A method didn't finish within the requested timeout.
+    #[error("request timed out after {0}ms")]
+    RequestTimeout(u64),
+    /// A timeout was requested for a method that can't be safely cancelled.
+    #[error("a timeout isn't supported for methods that lock, sign or submit outputs")]
+    TimeoutNotSupported,
+    /// Error of a method in a batch, with the index of the method that failed.
+    #[error("batch method {index} failed: {error}")]
+    BatchMethod { index: usize, error: Box<Self> },
 }
 
 #[cfg(feature = "stronghold")]
@@ -27,8 +27,15 @@ pub use self::method_handler::CallMethod;
 pub use self::{
     error::{Error, Result},
     method::{AccountMethod, ClientMethod, SecretManagerMethod, UtilsMethod, WalletMethod},
-    method_handler::{call_client_method, call_secret_manager_method, call_utils_method, call_wallet_method},
-    response::Response,
+    method_handler::{
+        call_client_method, call_secret_manager_method, call_utils_method, call_wallet_method, call_wallet_method_batch,
+    },
+    response::{
+        AccountsBalancesDto, BackgroundSyncStatusDto, Bip44PathDto, ClaimableOutputsSummaryDto, ClientConfigDto,
+        ClientNodeDto, DustReportDto, FoundryOutputDataDto, HistoryExportDto, Irc27Attribute, Irc27Metadata,
+        Irc30Metadata, OutputIdPartsDto, OutputsPageDto, ParsedBech32AddressDto, Response, RetriedTransactionsDto,
+        TransactionDryRunDto,
+    },
 };
 
 pub fn init_logger(config: String) -> std::result::Result<(), fern_logger::Error> {
@@ -13,7 +13,8 @@ use iota_sdk::{
         secret::GenerateAddressOptions,
     },
     types::block::{
-        output::{dto::OutputDto, OutputId, TokenId},
+        address::Bech32Address,
+        output::{dto::OutputDto, AliasId, OutputId, TokenId},
         payload::transaction::TransactionId,
     },
     wallet::{
@@ -21,7 +22,7 @@ use iota_sdk::{
             CreateAliasParamsDto, FilterOptions, MintNativeTokenParamsDto, MintNftParamsDto, OutputParamsDto,
             OutputsToClaim, SyncOptions, TransactionOptionsDto,
         },
-        SendAmountParams, SendNativeTokensParams, SendNftParams,
+        RecipientParams, SendAmountParams, SendNativeTokensParams, SendNftParams,
     },
     U256,
 };
@@ -42,6 +43,16 @@ pub enum AccountMethod {
         burn: BurnDto,
         options: Option<TransactionOptionsDto>,
     },
+    /// Prepare a transaction for the generic `burn()` function, without signing or submitting it.
+    ///
+    /// The same note as for [`Burn`](AccountMethod::Burn) applies: burning native tokens doesn't touch the foundries
+    /// `melted_tokens` field.
+    ///
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    PrepareBurn {
+        burn: BurnDto,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Consolidate outputs.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
@@ -49,6 +60,21 @@ pub enum AccountMethod {
         force: bool,
         output_consolidation_threshold: Option<usize>,
     },
+    /// Prepare a transaction to consolidate outputs, without signing or submitting it.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareConsolidateOutputs {
+        force: bool,
+        output_consolidation_threshold: Option<usize>,
+    },
+    /// Consolidate outputs holding native tokens, or only the ones holding `token_id` if it's provided, into as few
+    /// outputs as the native tokens count limit of an output allows.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    #[serde(rename_all = "camelCase")]
+    ConsolidateNativeTokens {
+        token_id: Option<TokenId>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Create an alias output.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
@@ -56,33 +82,76 @@ pub enum AccountMethod {
         params: Option<CreateAliasParamsDto>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Prepare a transaction to create an alias output, without signing or submitting it. The alias output has a null
+    /// alias id and holds the minimum required storage deposit.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareCreateAliasOutput {
+        params: Option<CreateAliasParamsDto>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Generate new unused addresses.
     /// Expected response: [`GeneratedAddress`](crate::Response::GeneratedAddress)
     GenerateAddresses {
         amount: u32,
         options: Option<GenerateAddressOptions>,
     },
+    /// Get the first address that never had an output, only generates a new address if there is none.
+    /// Expected response: [`GeneratedAddress`](crate::Response::GeneratedAddress)
+    GetNextUnusedAddress { options: Option<GenerateAddressOptions> },
     /// Get the [`OutputData`](iota_sdk::wallet::account::types::OutputData) of an output stored in the account
     /// Expected response: [`OutputData`](crate::Response::OutputData)
     #[serde(rename_all = "camelCase")]
     GetOutput { output_id: OutputId },
+    /// Get the [`OutputData`](iota_sdk::wallet::account::types::OutputData) of multiple outputs stored in the
+    /// account. Outputs that aren't stored in the account are skipped.
+    /// Expected response: [`OutputsData`](crate::Response::OutputsData)
+    #[serde(rename_all = "camelCase")]
+    GetOutputs { output_ids: Vec<OutputId> },
     /// Get the [`Output`](iota_sdk::types::block::output::Output) that minted a native token by its TokenId
     /// Expected response: [`Output`](crate::Response::Output)
     #[serde(rename_all = "camelCase")]
     GetFoundryOutput { token_id: TokenId },
+    /// Get the IRC-30 metadata of native tokens from the immutable metadata feature of their foundries. The metadata
+    /// is `None` for tokens whose foundry has no valid IRC-30 metadata.
+    /// Expected response: [`NativeTokensMetadata`](crate::Response::NativeTokensMetadata)
+    #[serde(rename_all = "camelCase")]
+    GetNativeTokensMetadata { token_ids: Vec<TokenId> },
     /// Get outputs with additional unlock conditions
     /// Expected response: [`OutputIds`](crate::Response::OutputIds)
     #[serde(rename_all = "camelCase")]
     GetOutputsWithAdditionalUnlockConditions { outputs_to_claim: OutputsToClaim },
+    /// Get a summary of what would be received by claiming the outputs with additional unlock conditions
+    /// Expected response: [`ClaimableSummary`](crate::Response::ClaimableSummary)
+    #[serde(rename_all = "camelCase")]
+    GetClaimableOutputsSummary { outputs_to_claim: OutputsToClaim },
     /// Get the [`Transaction`](iota_sdk::wallet::account::types::Transaction) of a transaction stored in the account
     /// Expected response: [`Transaction`](crate::Response::Transaction)
     #[serde(rename_all = "camelCase")]
     GetTransaction { transaction_id: TransactionId },
+    /// Get the inclusion state of a transaction stored in the account. The node is asked for the current state of
+    /// pending transactions.
+    /// Expected response: [`InclusionState`](crate::Response::InclusionState)
+    #[serde(rename_all = "camelCase")]
+    GetTransactionInclusionState { transaction_id: TransactionId },
+    /// Get the inclusion state of all pending transactions from the node, together with the number of times they got
+    /// reattached.
+    /// Expected response: [`PendingStatuses`](crate::Response::PendingStatuses)
+    GetPendingTransactionsStatus,
     /// Get the transaction with inputs of an incoming transaction stored in the account
     /// List might not be complete, if the node pruned the data already
     /// Expected response: [`Transaction`](crate::Response::Transaction)
     #[serde(rename_all = "camelCase")]
     GetIncomingTransaction { transaction_id: TransactionId },
+    /// Get the transaction that created an output, from the transactions or incoming transactions stored in the
+    /// account. Returns `None` if it isn't stored, for example because the node pruned it already.
+    /// Expected response: [`Transaction`](crate::Response::Transaction)
+    #[serde(rename_all = "camelCase")]
+    GetOutputTransaction { output_id: OutputId },
+    /// Get the local note of a transaction stored in the account.
+    /// Expected response: [`TransactionNote`](crate::Response::TransactionNote)
+    #[serde(rename_all = "camelCase")]
+    GetTransactionNote { transaction_id: TransactionId },
     /// Expected response: [`Addresses`](crate::Response::Addresses)
     /// List addresses.
     Addresses,
@@ -90,14 +159,69 @@ pub enum AccountMethod {
     /// Expected response:
     /// [`AddressesWithUnspentOutputs`](crate::Response::AddressesWithUnspentOutputs)
     AddressesWithUnspentOutputs,
-    /// Returns all outputs of the account
-    /// Expected response: [`OutputsData`](crate::Response::OutputsData)
+    /// Returns only addresses of the account that never had an output, spent or unspent.
+    /// Expected response: [`Addresses`](crate::Response::Addresses)
+    GetUnusedAddresses,
+    /// Returns whether an address belongs to the account, public and internal addresses are checked, including the
+    /// next `address_gap_limit` addresses that weren't generated yet, by default 20.
+    /// Expected response: [`Bool`](crate::Response::Bool)
+    #[serde(rename_all = "camelCase")]
+    IsAddressMine {
+        address: Bech32Address,
+        address_gap_limit: Option<u32>,
+    },
+    /// Returns all addresses of the account with their BIP44 derivation path.
+    /// Expected response: [`AddressesWithPaths`](crate::Response::AddressesWithPaths)
+    GetAddressesWithPaths,
+    /// Returns all outputs of the account. If `offset` or `limit` is set, only a page of the outputs ordered by
+    /// output id is returned.
+    /// Expected response: [`OutputsData`](crate::Response::OutputsData), or
+    /// [`OutputsPage`](crate::Response::OutputsPage) if paginated
     #[serde(rename_all = "camelCase")]
-    Outputs { filter_options: Option<FilterOptions> },
-    /// Returns all unspent outputs of the account
+    Outputs {
+        filter_options: Option<FilterOptions>,
+        /// The number of outputs to skip
+        #[serde(default)]
+        offset: Option<usize>,
+        /// The maximum number of outputs to return
+        #[serde(default)]
+        limit: Option<usize>,
+    },
+    /// Returns all unspent outputs of the account. If `offset` or `limit` is set, only a page of the outputs ordered
+    /// by output id is returned.
+    /// Expected response: [`OutputsData`](crate::Response::OutputsData), or
+    /// [`OutputsPage`](crate::Response::OutputsPage) if paginated
+    #[serde(rename_all = "camelCase")]
+    UnspentOutputs {
+        filter_options: Option<FilterOptions>,
+        /// The number of outputs to skip
+        #[serde(default)]
+        offset: Option<usize>,
+        /// The maximum number of outputs to return
+        #[serde(default)]
+        limit: Option<usize>,
+    },
+    /// Returns a page of the spent outputs of the account, ordered by output id.
+    /// Expected response: [`OutputsPage`](crate::Response::OutputsPage)
+    GetSpentOutputs {
+        /// The number of outputs to skip
+        #[serde(default)]
+        offset: Option<usize>,
+        /// The maximum number of outputs to return
+        #[serde(default)]
+        limit: Option<usize>,
+    },
+    /// Returns only the unspent NFT outputs of the account. The NFT id is set for outputs that minted an NFT.
     /// Expected response: [`OutputsData`](crate::Response::OutputsData)
     #[serde(rename_all = "camelCase")]
-    UnspentOutputs { filter_options: Option<FilterOptions> },
+    GetNftOutputs { filter_options: Option<FilterOptions> },
+    /// Returns only the unspent foundry outputs of the account, together with the id of the token they control.
+    /// Expected response: [`FoundryOutputsData`](crate::Response::FoundryOutputsData)
+    GetFoundryOutputs,
+    /// Returns the unspent outputs of the account that are still timelocked, together with the unix timestamp at
+    /// which they unlock.
+    /// Expected response: [`TimelockedOutputs`](crate::Response::TimelockedOutputs)
+    GetTimelockedOutputs,
     /// Returns all incoming transactions of the account
     /// Expected response:
     /// [`Transactions`](crate::Response::Transactions)
@@ -108,9 +232,18 @@ pub enum AccountMethod {
     /// Returns all pending transactions of the account
     /// Expected response: [`Transactions`](crate::Response::Transactions)
     PendingTransactions,
+    /// Exports the transactions and outputs of the account, all read from the same state of the account.
+    /// Expected response: [`HistoryExport`](crate::Response::HistoryExport)
+    #[serde(rename_all = "camelCase")]
+    ExportHistory {
+        /// Whether to also export the incoming transactions
+        #[serde(default)]
+        include_incoming: bool,
+    },
     /// Melt native tokens. This happens with the foundry output which minted them, by increasing it's
-    /// `melted_tokens` field.
-    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    /// `melted_tokens` field. Like [`IncreaseNativeTokenSupply`](AccountMethod::IncreaseNativeTokenSupply), the
+    /// response contains the token id together with the transaction.
+    /// Expected response: [`MintTokenTransaction`](crate::Response::MintTokenTransaction)
     #[serde(rename_all = "camelCase")]
     DecreaseNativeTokenSupply {
         /// Native token id
@@ -140,6 +273,14 @@ pub enum AccountMethod {
         params: MintNativeTokenParamsDto,
         options: Option<TransactionOptionsDto>,
     },
+    /// Prepare a transaction to mint a native token, without signing or submitting it. The outputs contain the new
+    /// foundry output, from which the id of the minted token can be derived.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareMintNativeToken {
+        params: MintNativeTokenParamsDto,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Mint nft.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
@@ -147,9 +288,39 @@ pub enum AccountMethod {
         params: Vec<MintNftParamsDto>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Prepare a transaction to mint NFTs, without signing or submitting it. The NFTs are minted in outputs with a
+    /// null NFT id, the id of each NFT is derived from the id of its output once the transaction is signed.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareMintNfts {
+        params: Vec<MintNftParamsDto>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Mint an NFT collection: the issuer NFT is minted first and, once it's included, the items are minted with
+    /// their issuer feature set to it. Fails before sending anything if the items don't fit into one transaction.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction) of the items
+    #[serde(rename_all = "camelCase")]
+    MintNftCollection {
+        issuer_nft_params: MintNftParamsDto,
+        items: Vec<MintNftParamsDto>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Get account balance information.
     /// Expected response: [`Balance`](crate::Response::Balance)
     GetBalance,
+    /// Get account balance information as if the current time was `unix_time`, so timelocked outputs that are
+    /// unlocked by then are available.
+    /// Expected response: [`Balance`](crate::Response::Balance)
+    #[serde(rename_all = "camelCase")]
+    GetSpendableAtTime { unix_time: u32 },
+    /// Get the unspent basic outputs with an amount at or below `threshold`, only holding base coins and an address
+    /// unlock condition, together with the storage deposit that consolidating them would free. Without a threshold,
+    /// outputs that only hold their minimum storage deposit are reported.
+    /// Expected response: [`DustReport`](crate::Response::DustReport)
+    GetDustReport { threshold: Option<String> },
+    /// Get the balance of each provided address, or of each address with unspent outputs if none are provided.
+    /// Expected response: [`AddressesBalances`](crate::Response::AddressesBalances)
+    GetAddressesBalances { addresses: Option<Vec<Bech32Address>> },
     /// Prepare an output.
     /// Expected response: [`Output`](crate::Response::Output)
     #[serde(rename_all = "camelCase")]
@@ -163,6 +334,20 @@ pub enum AccountMethod {
         outputs: Vec<OutputDto>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Run input selection for a transaction and summarize the result, without signing or submitting it. The selected
+    /// inputs are unlocked again afterwards, so they remain available for other transactions.
+    /// Expected response: [`TransactionDryRun`](crate::Response::TransactionDryRun)
+    DryRunTransaction {
+        outputs: Vec<OutputDto>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Run input selection for a transaction like [`PrepareTransaction`](AccountMethod::PrepareTransaction) and only
+    /// return the number of selected inputs. The inputs are unlocked again afterwards.
+    /// Expected response: [`Count`](crate::Response::Count)
+    EstimateInputCount {
+        outputs: Vec<OutputDto>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Prepare send amount.
     /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
     #[serde(rename_all = "camelCase")]
@@ -170,6 +355,71 @@ pub enum AccountMethod {
         params: Vec<SendAmountParams>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Prepare sending amounts with a storage deposit return and an expiration unlock condition, so the funds are
+    /// returned if they aren't claimed in time.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareSendWithExpiration {
+        params: Vec<SendAmountParams>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Prepare sending an amount in a basic output with optional hex encoded metadata and tag features. The amount
+    /// has to cover the storage deposit, which is increased by the features.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    PrepareSendWithMetadata {
+        address: Bech32Address,
+        amount: String,
+        metadata: Option<String>,
+        tag: Option<String>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Prepare a state transition of an alias output, which increments its state index and can update its hex encoded
+    /// state metadata and its sender feature.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareAliasStateTransition {
+        alias_id: AliasId,
+        new_state_metadata: Option<String>,
+        new_sender: Option<Bech32Address>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Prepare a governance transition of an alias output, which changes its state controller and/or governor while
+    /// keeping the state index.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareGovernanceTransition {
+        alias_id: AliasId,
+        new_state_controller: Option<Bech32Address>,
+        new_governor: Option<Bech32Address>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Prepare sending a basic output to every recipient, each with its own amount, native tokens and optional
+    /// expiration. Every amount has to cover the storage deposit of its output.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    PrepareSendBatch {
+        recipients: Vec<RecipientParams>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Prepare sending `count` basic outputs of `amount_each` to an address. Every output has to cover its storage
+    /// deposit.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareSplitOutput {
+        amount_each: String,
+        count: u32,
+        address: Bech32Address,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Prepare sending all available base coins, and optionally native tokens, to a single output on the destination
+    /// address. Outputs that can't be unlocked now or have a storage deposit return are skipped.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareSweep {
+        destination: Bech32Address,
+        #[serde(default)]
+        include_native_tokens: bool,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
     /// included (referenced by a milestone). Returns the included block id.
     /// Expected response: [`BlockId`](crate::Response::BlockId)
@@ -177,6 +427,18 @@ pub enum AccountMethod {
     RetryTransactionUntilIncluded {
         /// Transaction id
         transaction_id: TransactionId,
+        /// Interval in seconds, defaults to the account's default retry options and is at least
+        /// [`MIN_RETRY_UNTIL_INCLUDED_INTERVAL`](iota_sdk::wallet::account::MIN_RETRY_UNTIL_INCLUDED_INTERVAL)
+        interval: Option<u64>,
+        /// Maximum attempts, defaults to the account's default retry options and can't be zero
+        max_attempts: Option<u64>,
+    },
+    /// Retries all pending transactions one after the other until they're included, like
+    /// [`RetryTransactionUntilIncluded`](AccountMethod::RetryTransactionUntilIncluded). Failing transactions don't
+    /// stop the others, they're reported with their error.
+    /// Expected response: [`RetriedTransactions`](crate::Response::RetriedTransactions)
+    #[serde(rename_all = "camelCase")]
+    RetryAllPendingTransactions {
         /// Interval
         interval: Option<u64>,
         /// Maximum attempts
@@ -189,6 +451,10 @@ pub enum AccountMethod {
         /// Sync options
         options: Option<SyncOptions>,
     },
+    /// Returns when the account was synced the last time and up to which milestone, together with the latest
+    /// milestone of the node. If the node can't be reached, only the data of the last sync is returned.
+    /// Expected response: [`SyncStatus`](crate::Response::SyncStatus)
+    GetSyncStatus,
     /// Send amount.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
@@ -203,6 +469,13 @@ pub enum AccountMethod {
         params: Vec<SendNativeTokensParams>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Prepare sending native tokens, without signing or submitting the transaction.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareSendNativeTokens {
+        params: Vec<SendNativeTokensParams>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Send nft.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
@@ -210,6 +483,13 @@ pub enum AccountMethod {
         params: Vec<SendNftParams>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Prepare sending NFTs, without signing or submitting the transaction.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareSendNft {
+        params: Vec<SendNftParams>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Set the alias of the account.
     /// Expected response: [`Ok`](crate::Response::Ok)
     SetAlias { alias: String },
@@ -217,12 +497,40 @@ pub enum AccountMethod {
     /// If storage is enabled, will persist during restarts.
     /// Expected response: [`Ok`](crate::Response::Ok)
     SetDefaultSyncOptions { options: SyncOptions },
+    /// Get the fallback SyncOptions for account syncing, including the ones persisted in storage.
+    /// Expected response: [`SyncOptions`](crate::Response::SyncOptions)
+    GetDefaultSyncOptions,
+    /// Set the fallback interval and maximum attempts for retrying transactions until they're included, used when
+    /// none are provided.
+    /// If storage is enabled, will persist during restarts.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    SetDefaultRetryOptions { interval: u64, max_attempts: u64 },
+    /// Set a local note for a sent or incoming transaction stored in the account. Notes are never sent to the network.
+    /// If storage is enabled, will persist during restarts.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    SetTransactionNote {
+        transaction_id: TransactionId,
+        note: String,
+    },
     /// Send outputs in a transaction.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     SendOutputs {
         outputs: Vec<OutputDto>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Sign a message with the ed25519 key of an account address.
+    /// Expected response: [`Ed25519Signature`](crate::Response::Ed25519Signature)
+    #[serde(rename_all = "camelCase")]
+    SignMessage {
+        /// Address index
+        address_index: u32,
+        /// Internal address
+        internal: bool,
+        /// The message to sign, hex encoded String
+        message: String,
+    },
     /// Sign a prepared transaction.
     /// Expected response: [`SignedTransactionData`](crate::Response::SignedTransactionData)
     #[serde(rename_all = "camelCase")]
@@ -235,10 +543,25 @@ pub enum AccountMethod {
     SubmitAndStoreTransaction {
         signed_transaction_data: SignedTransactionDataDto,
     },
+    /// Serialize the transaction payload of a signed transaction, so it can be submitted elsewhere.
+    /// Expected response: [`Raw`](crate::Response::Raw)
+    #[serde(rename_all = "camelCase")]
+    SignedTransactionToBytes {
+        signed_transaction_data: SignedTransactionDataDto,
+    },
+    /// Submit the hex encoded bytes of a signed transaction payload, for example serialized with
+    /// [`SignedTransactionToBytes`](crate::method::AccountMethod::SignedTransactionToBytes) on another device, and
+    /// store it in the account. The transaction must be for the network of the connected node.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    SubmitRawTransaction { bytes: String },
     /// Claim outputs.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
     ClaimOutputs { output_ids_to_claim: Vec<OutputId> },
+    /// Prepare a transaction claiming the provided outputs. Fails if one of them can't be claimed.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareClaimOutputs { output_ids_to_claim: Vec<OutputId> },
     /// Vote for a participation event.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[cfg(feature = "participation")]
@@ -269,6 +592,21 @@ pub enum AccountMethod {
     GetParticipationOverview {
         event_ids: Option<Vec<ParticipationEventId>>,
     },
+    /// Returns the events the account is currently voting in, with the answers that were cast. Only the voting output
+    /// is read, no node is queried.
+    /// Expected response: [`ActiveVotes`](crate::Response::ActiveVotes)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    GetActiveVotes,
+    /// Calculates the staking rewards accrued by the account's outputs, per event. If event_ids are provided, only
+    /// return rewards for them. Voting events don't accrue rewards and are returned with 0.
+    /// Expected response: [`ParticipationRewards`](crate::Response::ParticipationRewards)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[serde(rename_all = "camelCase")]
+    GetParticipationRewards {
+        event_ids: Option<Vec<ParticipationEventId>>,
+    },
     /// Designates a given amount of tokens towards an account's "voting power" by creating a
     /// special output, which is really a basic one with some metadata.
     /// This will stop voting in most cases (if there is a remainder output), but the voting data isn't lost and
@@ -277,6 +615,12 @@ pub enum AccountMethod {
     #[cfg(feature = "participation")]
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     IncreaseVotingPower { amount: String },
+    /// Prepare designating a given amount of tokens towards an account's "voting power", without signing or
+    /// submitting the transaction.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    PrepareIncreaseVotingPower { amount: String },
     /// Reduces an account's "voting power" by a given amount.
     /// This will stop voting, but the voting data isn't lost and calling `Vote` without parameters will revote.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
@@ -292,6 +636,12 @@ pub enum AccountMethod {
     RegisterParticipationEvents {
         options: ParticipationEventRegistrationOptions,
     },
+    /// Queries all nodes for their events and stores the union of them locally, together with the nodes that reported
+    /// each event.
+    /// Expected response: [`ParticipationEvents`](crate::Response::ParticipationEvents)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    RegisterParticipationEventsFromNodes { nodes: Vec<Node> },
     /// Removes a previously registered participation event from local storage.
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "participation")]
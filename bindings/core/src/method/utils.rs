@@ -1,17 +1,29 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::BTreeMap;
+
 use derivative::Derivative;
-use iota_sdk::types::block::{
-    address::{dto::Ed25519AddressDto, AliasAddress},
-    output::{AliasId, NftId, OutputId},
-    payload::transaction::dto::{TransactionEssenceDto, TransactionPayloadDto},
-    signature::dto::Ed25519SignatureDto,
-    BlockDto,
+use iota_sdk::{
+    client::AmountUnit,
+    types::block::{
+        address::{dto::Ed25519AddressDto, AliasAddress},
+        output::{
+            dto::{OutputDto, RentStructureDto},
+            AliasId, NftId, OutputId,
+        },
+        payload::transaction::{
+            dto::{TransactionEssenceDto, TransactionPayloadDto},
+            TransactionId,
+        },
+        protocol::dto::ProtocolParametersDto,
+        signature::dto::Ed25519SignatureDto,
+        BlockDto,
+    },
 };
 use serde::{Deserialize, Serialize};
 
-use crate::OmittedDebug;
+use crate::{response::Irc27Attribute, OmittedDebug};
 
 /// Each public utils method.
 #[derive(Clone, Derivative, Serialize, Deserialize)]
@@ -55,7 +67,17 @@ pub enum UtilsMethod {
         /// Human readable part
         bech32_hrp: String,
     },
-    /// Returns a valid Address parsed from a String.
+    /// Re-encodes a bech32 encoded address with another human readable part
+    /// Expected response: [`Bech32Address`](crate::Response::Bech32Address)
+    #[serde(rename_all = "camelCase")]
+    ChangeBech32Hrp {
+        /// Bech32 encoded address
+        address: String,
+        /// New human readable part
+        new_hrp: String,
+    },
+    /// Returns a valid Address parsed from a String, together with its human readable part.
+    /// Expected response: [`ParsedBech32Address`](crate::Response::ParsedBech32Address)
     ParseBech32Address {
         /// Address
         address: String,
@@ -68,6 +90,7 @@ pub enum UtilsMethod {
     /// Generates a new mnemonic.
     GenerateMnemonic,
     /// Returns a hex encoded seed for a mnemonic.
+    /// Expected response: [`MnemonicHexSeed`](crate::Response::MnemonicHexSeed)
     MnemonicToHexSeed {
         /// Mnemonic
         #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
@@ -78,24 +101,63 @@ pub enum UtilsMethod {
         /// Block
         block: BlockDto,
     },
+    /// Returns the block ID of a block after validating it against the protocol parameters, unlike
+    /// [`BlockId`](UtilsMethod::BlockId) which accepts any block.
+    /// Expected response: [`BlockId`](crate::Response::BlockId)
+    #[serde(rename_all = "camelCase")]
+    ComputeBlockId {
+        /// Block
+        block: BlockDto,
+        /// Protocol parameters of the network the block belongs to
+        protocol_parameters: ProtocolParametersDto,
+    },
     /// Returns the transaction ID (Blake2b256 hash of the provided transaction payload)
     TransactionId {
         /// Transaction Payload
         payload: TransactionPayloadDto,
     },
-    /// Computes the alias ID
+    /// Returns the transaction ID of a transaction payload after validating it against the protocol parameters,
+    /// unlike [`TransactionId`](UtilsMethod::TransactionId) which accepts any payload.
+    /// Expected response: [`TransactionId`](crate::Response::TransactionId)
+    #[serde(rename_all = "camelCase")]
+    ComputeTransactionId {
+        /// Transaction Payload
+        payload: TransactionPayloadDto,
+        /// Protocol parameters of the network the transaction belongs to
+        protocol_parameters: ProtocolParametersDto,
+    },
+    /// Computes the output ID from a transaction ID and an output index
+    /// Expected response: [`OutputId`](crate::Response::OutputId)
+    #[serde(rename_all = "camelCase")]
+    ComputeOutputId {
+        /// Transaction ID
+        transaction_id: TransactionId,
+        /// Output index
+        index: u16,
+    },
+    /// Splits an output ID into the ID of the transaction that created the output and the output index
+    /// Expected response: [`OutputIdParts`](crate::Response::OutputIdParts)
+    #[serde(rename_all = "camelCase")]
+    OutputIdToTransactionIdAndIndex {
+        /// Output ID
+        output_id: OutputId,
+    },
+    /// Computes the alias ID, which is the hash of the output ID that created an alias output with a null alias ID
+    /// Expected response: [`AliasId`](crate::Response::AliasId)
     #[serde(rename_all = "camelCase")]
     ComputeAliasId {
         /// Output ID
         output_id: OutputId,
     },
-    /// Computes the NFT ID
+    /// Computes the NFT ID, which is the hash of the output ID that created an NFT output with a null NFT ID
+    /// Expected response: [`NftId`](crate::Response::NftId)
     #[serde(rename_all = "camelCase")]
     ComputeNftId {
         /// Output ID
         output_id: OutputId,
     },
     /// Computes the Foundry ID
+    /// Expected response: [`FoundryId`](crate::Response::FoundryId)
     #[serde(rename_all = "camelCase")]
     ComputeFoundryId {
         /// Alias address
@@ -105,19 +167,56 @@ pub enum UtilsMethod {
         /// Token scheme kind
         token_scheme_kind: u8,
     },
+    /// Computes the token ID of the foundry that is controlled by an alias
+    /// Expected response: [`TokenId`](crate::Response::TokenId)
+    #[serde(rename_all = "camelCase")]
+    ComputeTokenId {
+        /// Alias ID
+        alias_id: AliasId,
+        /// Serial number
+        serial_number: u32,
+        /// Token scheme kind
+        token_scheme_kind: u8,
+    },
     /// Compute the hash of a transaction essence.
     HashTransactionEssence {
         /// The transaction essence
         essence: TransactionEssenceDto,
     },
-    /// Verifies the Ed25519Signature for a message against an Ed25519Address.
+    /// Computes the Blake2b256 hash of some data.
+    /// Expected response: [`Hash`](crate::Response::Hash)
+    Blake2b256 {
+        /// The data to hash, hex encoded String
+        data: String,
+    },
+    /// Verifies the Ed25519Signature for a message, and optionally that its public key belongs to an Ed25519Address.
+    /// Malformed public keys or signatures return an error, a signature that doesn't match returns `false`.
+    /// Expected response: [`Bool`](crate::Response::Bool)
     VerifyEd25519Signature {
         /// The Ed25519 Signature
         signature: Ed25519SignatureDto,
         /// The signed message, hex encoded String
         message: String,
         /// The hex encoded Ed25519 address
-        address: Ed25519AddressDto,
+        address: Option<Ed25519AddressDto>,
+    },
+    /// Derives the ed25519 address of a hex encoded seed for a BIP-44 path and encodes it as bech32.
+    /// Expected response: [`Bech32Address`](crate::Response::Bech32Address)
+    #[serde(rename_all = "camelCase")]
+    DeriveAddress {
+        /// Hex encoded seed
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        seed_hex: String,
+        /// Coin type
+        coin_type: u32,
+        /// Account index
+        account_index: u32,
+        /// Internal address
+        internal: bool,
+        /// Address index
+        address_index: u32,
+        /// Human readable part
+        bech32_hrp: String,
     },
     /// Checks if the given mnemonic is valid.
     /// Expected response: [`Ok`](crate::Response::Ok)
@@ -125,4 +224,80 @@ pub enum UtilsMethod {
         #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
         mnemonic: String,
     },
+    /// Converts a base token amount between units, e.g. from glow to SMR. Fails if the result would contain a fraction
+    /// of the smallest unit.
+    /// Expected response: [`Amount`](crate::Response::Amount)
+    ConvertAmount {
+        /// The amount as decimal string
+        amount: String,
+        /// The unit of the amount
+        from: AmountUnit,
+        /// The unit to convert to
+        to: AmountUnit,
+    },
+    /// Computes the minimum required storage deposit of an output for the given rent structure, without a node.
+    /// Expected response:
+    /// [`MinimumRequiredStorageDeposit`](crate::Response::MinimumRequiredStorageDeposit)
+    #[serde(rename_all = "camelCase")]
+    ComputeStorageDeposit {
+        /// The output
+        output: OutputDto,
+        /// The rent structure of the network
+        rent_structure: RentStructureDto,
+    },
+    /// Checks that an output is valid for the given protocol parameters, including that its amount covers the
+    /// storage deposit, without a node.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    ValidateOutput {
+        /// The output
+        output: OutputDto,
+        /// Protocol parameters of the network the output belongs to
+        protocol_parameters: ProtocolParametersDto,
+    },
+    /// Parses the hex encoded data of an NFT's immutable metadata feature as IRC-27 metadata. Fails if the
+    /// `standard` field isn't `IRC27`.
+    /// Expected response: [`Irc27Metadata`](crate::Response::Irc27Metadata)
+    ParseIrc27Metadata {
+        /// The hex encoded metadata
+        metadata: String,
+    },
+    /// Builds IRC-30 native token metadata, hex encoded to be used as a foundry's immutable metadata feature.
+    /// Expected response: [`MetadataHex`](crate::Response::MetadataHex)
+    #[serde(rename_all = "camelCase")]
+    BuildIrc30Metadata {
+        /// The human readable name of the token
+        name: String,
+        /// The symbol of the token
+        symbol: String,
+        /// The number of decimals of the token
+        decimals: u32,
+        /// A description of the token
+        description: Option<String>,
+        /// A URL pointing to a website related to the token
+        url: Option<String>,
+        /// A URL pointing to the logo of the token
+        logo_url: Option<String>,
+    },
+    /// Builds IRC-27 NFT metadata, hex encoded to be used as an NFT's immutable metadata feature.
+    /// Expected response: [`MetadataHex`](crate::Response::MetadataHex)
+    #[serde(rename_all = "camelCase")]
+    BuildIrc27Metadata {
+        /// The MIME type of the NFT file, e.g. `image/png`
+        nft_type: String,
+        /// The URL pointing to the NFT file
+        uri: String,
+        /// The human readable name of the NFT
+        name: String,
+        /// The human readable name of the collection
+        collection_name: Option<String>,
+        /// The royalty percentages, by bech32 address of the receiver
+        royalties: Option<BTreeMap<String, f64>>,
+        /// The human readable name of the creator
+        issuer_name: Option<String>,
+        /// A description of the NFT
+        description: Option<String>,
+        /// The attributes of the NFT
+        attributes: Option<Vec<Irc27Attribute>>,
+    },
 }
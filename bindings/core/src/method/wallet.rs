@@ -9,9 +9,10 @@ use derivative::Derivative;
 use iota_sdk::wallet::events::types::{WalletEvent, WalletEventType};
 use iota_sdk::{
     client::{node_manager::node::NodeAuth, secret::GenerateAddressOptions},
+    types::block::address::Bech32Address,
     wallet::{
         account::{types::AccountIdentifier, SyncOptions},
-        ClientOptions,
+        ClientOptions, WalletDataJson,
     },
     Url,
 };
@@ -35,16 +36,52 @@ pub enum WalletMethod {
         /// The bech32 HRP.
         bech32_hrp: Option<String>,
     },
+    /// Creates a watch-only account that tracks the given addresses. It can be synced, but signing with it fails.
+    /// Expected response: [`Account`](crate::Response::Account)
+    CreateWatchAccount {
+        /// The addresses to track.
+        addresses: Vec<Bech32Address>,
+        /// The account alias.
+        alias: Option<String>,
+    },
     /// Read account.
     /// Expected response: [`Account`](crate::Response::Account)
     #[serde(rename_all = "camelCase")]
     GetAccount { account_id: AccountIdentifier },
+    /// Read account by its index, without trying to interpret it as an alias.
+    /// Expected response: [`Account`](crate::Response::Account)
+    GetAccountByIndex { index: u32 },
+    /// Find the account that generated an address, public and internal addresses are considered.
+    /// Expected response: [`Account`](crate::Response::Account)
+    FindAccountForAddress { address: Bech32Address },
     /// Return the account indexes.
     /// Expected response: [`AccountIndexes`](crate::Response::AccountIndexes)
     GetAccountIndexes,
     /// Read accounts.
     /// Expected response: [`Accounts`](crate::Response::Accounts)
     GetAccounts,
+    /// Import accounts from plain JSON data without secret material. The imported accounts are watch-only: they can
+    /// be synced, but signing with them fails.
+    /// Expected response: [`Accounts`](crate::Response::Accounts)
+    ImportAccounts { data: WalletDataJson },
+    /// Sync all accounts concurrently, a few at a time, and return their balances by account index. An account that
+    /// fails to sync doesn't stop the others, it's returned with the reason instead.
+    /// Expected response: [`Balances`](crate::Response::Balances)
+    SyncAllAccounts {
+        /// Sync options
+        options: Option<SyncOptions>,
+    },
+    /// Set the fallback SyncOptions for all accounts, also for accounts that will be created later.
+    /// If storage is enabled, will persist during restarts.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    SetDefaultSyncOptionsForAll { options: SyncOptions },
+    /// Get the balance of all accounts added together, optionally syncing them first.
+    /// Expected response: [`Balance`](crate::Response::Balance)
+    GetTotalBalance {
+        /// Sync all accounts before computing the balance, fails if any of them can't be synced
+        #[serde(default)]
+        sync: bool,
+    },
     /// Consume an account method.
     /// Returns [`Response`](crate::Response)
     #[serde(rename_all = "camelCase")]
@@ -53,6 +90,14 @@ pub enum WalletMethod {
         account_id: AccountIdentifier,
         /// The account method to call.
         method: AccountMethod,
+        /// Timeout in milliseconds for the account method, after which a
+        /// [`RequestTimeout`](crate::Error::RequestTimeout) error is returned. Only supported for syncing, which is
+        /// aborted at the next address batch so the account isn't left partially updated, and methods that only read
+        /// data, because cancelling a method that locks, signs or submits outputs could leave outputs
+        /// locked or a submitted transaction unreported, so for other methods a
+        /// [`TimeoutNotSupported`](crate::Error::TimeoutNotSupported) error is returned.
+        #[serde(default)]
+        timeout_ms: Option<u64>,
     },
     /// Backup storage. Password must be the current one, when Stronghold is used as SecretManager.
     /// Expected response: [`Ok`](crate::Response::Ok)
@@ -65,6 +110,21 @@ pub enum WalletMethod {
         #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
         password: String,
     },
+    /// Backup storage with only the given accounts. Password must be the current one, when Stronghold is used as
+    /// SecretManager.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    #[serde(rename_all = "camelCase")]
+    BackupAccounts {
+        /// The backup destination.
+        destination: PathBuf,
+        /// Stronghold file password.
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        password: String,
+        /// The accounts to backup.
+        account_ids: Vec<AccountIdentifier>,
+    },
     /// Change the Stronghold password to another one and also re-encrypt the values in the loaded snapshot with it.
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "stronghold")]
@@ -87,6 +147,12 @@ pub enum WalletMethod {
     #[cfg(feature = "stronghold")]
     #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
     IsStrongholdPasswordAvailable,
+    /// Get the time in milliseconds until the Stronghold password is cleared from memory, `None` if no password is
+    /// set or no clear interval is configured.
+    /// Expected response: [`Duration`](crate::Response::Duration)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    GetStrongholdPasswordClearCountdown,
     /// Find accounts with unspent outputs
     /// Expected response: [`Accounts`](crate::Response::Accounts)
     #[serde(rename_all = "camelCase")]
@@ -102,6 +168,18 @@ pub enum WalletMethod {
         /// fields will be overwritten to skip existing addresses.
         sync_options: Option<SyncOptions>,
     },
+    /// Read the coin type, the number of accounts and whether a mnemonic is stored from a Stronghold backup, without
+    /// restoring it. Neither the wallet nor the active Stronghold file are modified.
+    /// Expected response: [`BackupContents`](crate::Response::BackupContents)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    InspectBackup {
+        /// The path to the backed up Stronghold.
+        source: PathBuf,
+        /// Stronghold file password.
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        password: String,
+    },
     /// Restore a backup from a Stronghold file
     /// Replaces client_options, coin_type, secret_manager and accounts. Returns an error if accounts were already
     /// created If Stronghold is used as secret_manager, the existing Stronghold file will be overwritten. If a
@@ -129,6 +207,17 @@ pub enum WalletMethod {
         /// accounts will be restored.
         ignore_if_bech32_mismatch: Option<String>,
     },
+    /// Removes an account, the indexes of the other accounts stay the same.
+    /// Fails if the account has unspent outputs or pending transactions, unless `force` is set.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    RemoveAccount {
+        /// The account identifier.
+        account_id: AccountIdentifier,
+        /// Remove the account even if it has unspent outputs or pending transactions.
+        #[serde(default)]
+        force: bool,
+    },
     /// Removes the latest account (account with the largest account index).
     /// Expected response: [`Ok`](crate::Response::Ok)
     RemoveLatestAccount,
@@ -136,6 +225,17 @@ pub enum WalletMethod {
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[serde(rename_all = "camelCase")]
     SetClientOptions { client_options: Box<ClientOptions> },
+    /// Sets the coin type of the wallet and all accounts. Existing addresses become stale: only the first public
+    /// address of each account is regenerated, other addresses must be generated again and the accounts resynced.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    SetCoinType {
+        /// The new coin type.
+        coin_type: u32,
+        /// Change the coin type even if accounts have unspent outputs or pending transactions.
+        #[serde(default)]
+        force: bool,
+    },
     /// Generate an address without storing it
     /// Expected response: [`Bech32Address`](crate::Response::Bech32Address)
     #[serde(rename_all = "camelCase")]
@@ -162,6 +262,16 @@ pub enum WalletMethod {
         #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
         password: String,
     },
+    /// Switch to the Stronghold snapshot at another path, e.g. after moving the file. The password must be the current
+    /// one. Fails if the file doesn't exist or doesn't match the coin type and seed of the wallet.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    SetStrongholdSnapshotPath {
+        path: PathBuf,
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        password: String,
+    },
     /// Set the stronghold password clear interval.
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "stronghold")]
@@ -188,17 +298,76 @@ pub enum WalletMethod {
     /// Stop background syncing.
     /// Expected response: [`Ok`](crate::Response::Ok)
     StopBackgroundSync,
+    /// Get if the background syncing is running, its interval and when all accounts got synced the last time.
+    /// Expected response: [`BackgroundSyncStatus`](crate::Response::BackgroundSyncStatus)
+    GetBackgroundSyncStatus,
+    /// Abort all syncs in progress at the next address batch, discarding their partial results. Returns once no
+    /// account is syncing anymore.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    AbortSync,
     /// Emits an event for testing if the event system is working
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "events")]
     #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
     EmitTestEvent { event: WalletEvent },
-    // Remove all listeners of this type. Empty vec clears all listeners
+    /// Emits a representative event of the given type for testing if the handlers for it are registered
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "events")]
     #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
     #[serde(rename_all = "camelCase")]
+    EmitTestEventOfType { event_type: WalletEventType },
+    /// Remove all listeners of these types and return how many were removed. Empty vec clears all listeners.
+    /// Expected response: [`Count`](crate::Response::Count)
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    #[serde(rename_all = "camelCase")]
     ClearListeners { event_types: Vec<WalletEventType> },
+    /// Get the event types that have listeners registered, with the amount of listeners for each type.
+    /// Expected response: [`ListenerTypes`](crate::Response::ListenerTypes)
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    GetActiveListeners,
+    /// Get the node info (name, version, protocol parameters, status) of the node the wallet's client uses, preferring
+    /// the primary node. Fails if no node is reachable.
+    /// Expected response: [`NodeInfo`](crate::Response::NodeInfo)
+    GetNodeInfo,
+    /// Query all configured nodes concurrently and return their health and confirmed milestone index. Nodes that
+    /// don't respond within the api timeout are reported as unhealthy.
+    /// Expected response: [`NodesHealth`](crate::Response::NodesHealth)
+    GetNodesHealth,
+    /// Returns the configured nodes with their url and whether they're disabled. Authentication secrets aren't
+    /// returned, only whether authentication is set.
+    /// Expected response: [`Nodes`](crate::Response::Nodes)
+    GetClientNodes,
+    /// Returns the client configuration currently in effect, like the quorum settings, timeouts and bech32 HRP.
+    /// Nodes and their authentication aren't included.
+    /// Expected response: [`ClientConfig`](crate::Response::ClientConfig)
+    GetClientConfig,
+    /// Add a node to the client options, or update it if a node with the same url is already configured. The other
+    /// client options stay the same.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    AddNode {
+        /// Node url
+        url: Url,
+        /// Authentication options
+        auth: Option<NodeAuth>,
+        /// Whether the node is disabled
+        #[serde(default)]
+        disabled: bool,
+    },
+    /// Remove a node from the client options. The last remaining node can't be removed.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    RemoveNode {
+        /// Node url
+        url: Url,
+    },
+    /// Set one of the configured nodes as primary node, which is used first for all requests, with the other nodes
+    /// as fallback.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    SetPrimaryNode {
+        /// Node url
+        url: Url,
+    },
     /// Update the authentication for the provided node.
     /// Expected response: [`Ok`](crate::Response::Ok)
     UpdateNodeAuth {
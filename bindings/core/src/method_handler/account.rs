@@ -1,29 +1,43 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-#[cfg(feature = "participation")]
-use std::str::FromStr;
+use std::{collections::BTreeMap, str::FromStr};
 
 use iota_sdk::{
-    client::api::{
-        input_selection::Burn, PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData,
-        SignedTransactionDataDto,
+    client::{
+        api::{
+            input_selection::Burn, PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData,
+            SignedTransactionDataDto,
+        },
+        secret::types::InputSigningData,
     },
     types::block::{
-        output::{dto::OutputDto, Output, Rent},
+        input::Input,
+        output::{dto::OutputDto, Output, Rent, TokenId},
+        payload::transaction::{TransactionEssence, TransactionPayload},
+        signature::dto::Ed25519SignatureDto,
         Error,
     },
     wallet::{
         account::{
-            types::{AccountBalanceDto, TransactionDto},
-            Account, CreateAliasParams, MintTokenTransactionDto, OutputDataDto, OutputParams, TransactionOptions,
+            types::{AccountBalanceDto, Transaction, TransactionDto},
+            Account, CreateAliasParams, MintTokenTransactionDto, OutputData, OutputDataDto, OutputParams, RetryOptions,
+            TransactionOptions,
         },
         MintNativeTokenParams, MintNftParams,
     },
 };
+use packable::PackableExt;
 use primitive_types::U256;
 
-use crate::{method::AccountMethod, Response, Result};
+use crate::{
+    method::AccountMethod,
+    response::{
+        Bip44PathDto, ClaimableOutputsSummaryDto, DustReportDto, FoundryOutputDataDto, HistoryExportDto, Irc30Metadata,
+        OutputsPageDto, RetriedTransactionsDto, TransactionDryRunDto,
+    },
+    Response, Result,
+};
 
 pub(crate) async fn call_account_method_internal(account: &Account, method: AccountMethod) -> Result<Response> {
     let response = match method {
@@ -36,6 +50,15 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::PrepareBurn { burn, options } => {
+            let data = account
+                .prepare_burn(
+                    Burn::try_from(&burn)?,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
         AccountMethod::ConsolidateOutputs {
             force,
             output_consolidation_threshold,
@@ -45,6 +68,24 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::PrepareConsolidateOutputs {
+            force,
+            output_consolidation_threshold,
+        } => {
+            let data = account
+                .prepare_consolidate_outputs(force, output_consolidation_threshold)
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
+        AccountMethod::ConsolidateNativeTokens { token_id, options } => {
+            let transaction = account
+                .consolidate_native_tokens(
+                    token_id,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
         AccountMethod::CreateAliasOutput { params, options } => {
             let params = params
                 .map(|options| CreateAliasParams::try_from(&options))
@@ -58,28 +99,121 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::PrepareCreateAliasOutput { params, options } => {
+            let params = params
+                .map(|options| CreateAliasParams::try_from(&options))
+                .transpose()?;
+
+            let data = account
+                .prepare_create_alias_output(
+                    params,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
         AccountMethod::GenerateAddresses { amount, options } => {
             let address = account.generate_addresses(amount, options).await?;
             Response::GeneratedAddress(address)
         }
+        AccountMethod::GetNextUnusedAddress { options } => {
+            let address = account.get_next_unused_address(options).await?;
+            Response::GeneratedAddress(vec![address])
+        }
         AccountMethod::GetOutputsWithAdditionalUnlockConditions { outputs_to_claim } => {
             let output_ids = account
                 .get_unlockable_outputs_with_additional_unlock_conditions(outputs_to_claim)
                 .await?;
             Response::OutputIds(output_ids)
         }
+        AccountMethod::GetClaimableOutputsSummary { outputs_to_claim } => {
+            let output_ids = account
+                .get_unlockable_outputs_with_additional_unlock_conditions(outputs_to_claim)
+                .await?;
+
+            let mut amount = 0;
+            let mut basic_outputs = 0;
+            let mut nft_outputs = 0;
+            let mut native_tokens = BTreeMap::<TokenId, U256>::new();
+
+            for output_id in &output_ids {
+                if let Some(output_data) = account.get_output(output_id).await {
+                    let output = &output_data.output;
+                    // The storage deposit needs to be returned when claiming, so it's not received
+                    let storage_deposit_return = output
+                        .unlock_conditions()
+                        .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+                        .map_or(0, |sdr| sdr.amount());
+                    amount += output.amount().saturating_sub(storage_deposit_return);
+
+                    match output {
+                        Output::Basic(_) => basic_outputs += 1,
+                        Output::Nft(_) => nft_outputs += 1,
+                        _ => {}
+                    }
+
+                    if let Some(output_native_tokens) = output.native_tokens() {
+                        for native_token in output_native_tokens.iter() {
+                            *native_tokens.entry(*native_token.token_id()).or_default() += native_token.amount();
+                        }
+                    }
+                }
+            }
+
+            Response::ClaimableSummary(ClaimableOutputsSummaryDto {
+                amount: amount.to_string(),
+                basic_outputs,
+                nft_outputs,
+                native_tokens,
+            })
+        }
         AccountMethod::GetOutput { output_id } => {
             let output_data = account.get_output(&output_id).await;
             Response::OutputData(output_data.as_ref().map(OutputDataDto::from).map(Box::new))
         }
+        AccountMethod::GetOutputs { output_ids } => {
+            let mut outputs = Vec::with_capacity(output_ids.len());
+            for output_id in &output_ids {
+                if let Some(output_data) = account.get_output(output_id).await {
+                    outputs.push(OutputDataDto::from(&output_data));
+                }
+            }
+            Response::OutputsData(outputs)
+        }
         AccountMethod::GetFoundryOutput { token_id } => {
             let output = account.get_foundry_output(token_id).await?;
             Response::Output(OutputDto::from(&output))
         }
+        AccountMethod::GetNativeTokensMetadata { token_ids } => {
+            let mut tokens_metadata = Vec::with_capacity(token_ids.len());
+            for token_id in token_ids {
+                let metadata = match account.get_foundry_output(token_id).await {
+                    Ok(Output::Foundry(foundry)) => foundry
+                        .immutable_features()
+                        .metadata()
+                        .and_then(|metadata| parse_irc_30_metadata(metadata.data())),
+                    Ok(_) => None,
+                    // An unknown foundry only has no metadata, the other tokens are still resolved
+                    Err(err) => {
+                        log::debug!("[GetNativeTokensMetadata] couldn't get foundry of {token_id}: {err}");
+                        None
+                    }
+                };
+                tokens_metadata.push((token_id, metadata));
+            }
+            Response::NativeTokensMetadata(tokens_metadata)
+        }
         AccountMethod::GetTransaction { transaction_id } => {
             let transaction = account.get_transaction(&transaction_id).await;
             Response::Transaction(transaction.as_ref().map(TransactionDto::from).map(Box::new))
         }
+        AccountMethod::GetTransactionInclusionState { transaction_id } => {
+            let inclusion_state = account.get_transaction_inclusion_state(&transaction_id).await?;
+            Response::InclusionState(inclusion_state)
+        }
+        AccountMethod::GetPendingTransactionsStatus => {
+            Response::PendingStatuses(account.pending_transactions_status().await?)
+        }
         AccountMethod::GetIncomingTransaction { transaction_id } => {
             let transaction = account.get_incoming_transaction(&transaction_id).await;
 
@@ -88,6 +222,17 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 |transaction| Response::Transaction(Some(Box::new(TransactionDto::from(&transaction)))),
             )
         }
+        AccountMethod::GetOutputTransaction { output_id } => {
+            let transaction_id = output_id.transaction_id();
+            let transaction = match account.get_transaction(transaction_id).await {
+                Some(transaction) => Some(transaction),
+                None => account.get_incoming_transaction(transaction_id).await,
+            };
+            Response::Transaction(transaction.as_ref().map(TransactionDto::from).map(Box::new))
+        }
+        AccountMethod::GetTransactionNote { transaction_id } => {
+            Response::TransactionNote(account.get_transaction_note(&transaction_id).await)
+        }
         AccountMethod::Addresses => {
             let addresses = account.addresses().await?;
             Response::Addresses(addresses)
@@ -96,13 +241,107 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let addresses = account.addresses_with_unspent_outputs().await?;
             Response::AddressesWithUnspentOutputs(addresses)
         }
-        AccountMethod::Outputs { filter_options } => {
+        AccountMethod::GetUnusedAddresses => {
+            let addresses = account.unused_addresses().await?;
+            Response::Addresses(addresses)
+        }
+        AccountMethod::IsAddressMine {
+            address,
+            address_gap_limit,
+        } => Response::Bool(account.is_address_mine(&address, address_gap_limit).await?),
+        AccountMethod::GetAddressesWithPaths => {
+            let (coin_type, account_index) = {
+                let account_details = account.details().await;
+                (*account_details.coin_type(), *account_details.index())
+            };
+            let addresses = account.addresses().await?;
+            Response::AddressesWithPaths(
+                addresses
+                    .into_iter()
+                    .map(|address| {
+                        let path = Bip44PathDto {
+                            coin_type,
+                            account: account_index,
+                            change: *address.internal() as u32,
+                            address_index: *address.key_index(),
+                        };
+                        (address.address().clone(), path)
+                    })
+                    .collect(),
+            )
+        }
+        AccountMethod::Outputs {
+            filter_options,
+            offset,
+            limit,
+        } => {
             let outputs = account.outputs(filter_options).await?;
-            Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect())
+            outputs_response(outputs, offset, limit)
         }
-        AccountMethod::UnspentOutputs { filter_options } => {
+        AccountMethod::UnspentOutputs {
+            filter_options,
+            offset,
+            limit,
+        } => {
             let outputs = account.unspent_outputs(filter_options).await?;
-            Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect())
+            outputs_response(outputs, offset, limit)
+        }
+        AccountMethod::GetSpentOutputs { offset, limit } => {
+            let outputs = account.spent_outputs(None).await?;
+            Response::OutputsPage(outputs_page(outputs, offset, limit))
+        }
+        AccountMethod::GetNftOutputs { filter_options } => {
+            let outputs = account.unspent_outputs(filter_options).await?;
+            Response::OutputsData(
+                outputs
+                    .iter()
+                    .filter_map(|output_data| {
+                        if let Output::Nft(nft) = &output_data.output {
+                            let mut output_data_dto = OutputDataDto::from(output_data);
+                            // Freshly minted NFTs still have a null id in the output itself
+                            if let OutputDto::Nft(nft_dto) = &mut output_data_dto.output {
+                                nft_dto.nft_id = nft.nft_id_non_null(&output_data.output_id);
+                            }
+                            Some(output_data_dto)
+                        } else {
+                            None
+                        }
+                    })
+                    .collect(),
+            )
+        }
+        AccountMethod::GetFoundryOutputs => {
+            let outputs = account.unspent_outputs(None).await?;
+            Response::FoundryOutputsData(
+                outputs
+                    .iter()
+                    .filter_map(|output_data| {
+                        if let Output::Foundry(foundry) = &output_data.output {
+                            Some(FoundryOutputDataDto {
+                                token_id: TokenId::from(foundry.id()),
+                                output_data: OutputDataDto::from(output_data),
+                            })
+                        } else {
+                            None
+                        }
+                    })
+                    .collect(),
+            )
+        }
+        AccountMethod::GetTimelockedOutputs => {
+            let current_time = account.client().get_time_checked().await?;
+            let outputs = account.unspent_outputs(None).await?;
+            Response::TimelockedOutputs(
+                outputs
+                    .iter()
+                    .filter_map(|output_data| {
+                        let timelock = output_data.output.unlock_conditions()?.timelock()?;
+                        // Outputs whose timelock already passed can be spent now
+                        (timelock.timestamp() > current_time)
+                            .then(|| (OutputDataDto::from(output_data), timelock.timestamp()))
+                    })
+                    .collect(),
+            )
         }
         AccountMethod::IncomingTransactions => {
             let transactions = account.incoming_transactions().await;
@@ -116,6 +355,21 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let transactions = account.pending_transactions().await;
             Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
         }
+        AccountMethod::ExportHistory { include_incoming } => {
+            // Hold the lock for the whole export, so the transactions and outputs can't change in between.
+            let account_details = account.details().await;
+
+            let mut outputs = account_details.outputs().values().collect::<Vec<_>>();
+            outputs.sort_unstable_by_key(|output_data| output_data.output_id);
+
+            Response::HistoryExport(HistoryExportDto {
+                timestamp: iota_sdk::utils::unix_timestamp_now().as_millis().to_string(),
+                transactions: sorted_transactions(account_details.transactions().values()),
+                incoming_transactions: include_incoming
+                    .then(|| sorted_transactions(account_details.incoming_transactions().values())),
+                outputs: outputs.into_iter().map(OutputDataDto::from).collect(),
+            })
+        }
         AccountMethod::DecreaseNativeTokenSupply {
             token_id,
             melt_amount,
@@ -128,7 +382,10 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                     options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
                 )
                 .await?;
-            Response::SentTransaction(TransactionDto::from(&transaction))
+            Response::MintTokenTransaction(MintTokenTransactionDto {
+                token_id,
+                transaction: TransactionDto::from(&transaction),
+            })
         }
         AccountMethod::IncreaseNativeTokenSupply {
             token_id,
@@ -153,6 +410,15 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::MintTokenTransaction(MintTokenTransactionDto::from(&transaction))
         }
+        AccountMethod::PrepareMintNativeToken { params, options } => {
+            let data = account
+                .prepare_mint_native_token(
+                    MintNativeTokenParams::try_from(&params)?,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
         AccountMethod::MinimumRequiredStorageDeposit { output } => {
             let output = Output::try_from_dto(&output, account.client().get_token_supply().await?)?;
             let rent_structure = account.client().get_rent_structure().await?;
@@ -173,7 +439,91 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::PrepareMintNfts { params, options } => {
+            let data = account
+                .prepare_mint_nfts(
+                    params
+                        .iter()
+                        .map(MintNftParams::try_from)
+                        .collect::<iota_sdk::wallet::Result<Vec<MintNftParams>>>()?,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
+        AccountMethod::MintNftCollection {
+            issuer_nft_params,
+            items,
+            options,
+        } => {
+            let transaction = account
+                .mint_nft_collection(
+                    MintNftParams::try_from(&issuer_nft_params)?,
+                    items
+                        .iter()
+                        .map(MintNftParams::try_from)
+                        .collect::<iota_sdk::wallet::Result<Vec<MintNftParams>>>()?,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
         AccountMethod::GetBalance => Response::Balance(AccountBalanceDto::from(&account.balance().await?)),
+        AccountMethod::GetSpendableAtTime { unix_time } => {
+            Response::Balance(AccountBalanceDto::from(&account.balance_at_time(unix_time).await?))
+        }
+        AccountMethod::GetDustReport { threshold } => {
+            let threshold = threshold
+                .map(|threshold| {
+                    u64::from_str(&threshold).map_err(|_| iota_sdk::client::Error::InvalidAmount(threshold.clone()))
+                })
+                .transpose()?;
+            let rent_structure = account.client().get_rent_structure().await?;
+
+            let mut dust_outputs = Vec::new();
+            let mut dust_amount = 0;
+            let mut total_storage_deposit = 0;
+            let mut min_storage_deposit = u64::MAX;
+
+            for output_data in account.unspent_outputs(None).await? {
+                if let Output::Basic(basic_output) = &output_data.output {
+                    // Other unlock conditions or native tokens prevent a simple consolidation
+                    if basic_output.unlock_conditions().len() != 1 || !basic_output.native_tokens().is_empty() {
+                        continue;
+                    }
+                    let storage_deposit = output_data.output.rent_cost(&rent_structure);
+                    if basic_output.amount() <= threshold.unwrap_or(storage_deposit) {
+                        dust_outputs.push(output_data.output_id);
+                        dust_amount += basic_output.amount();
+                        total_storage_deposit += storage_deposit;
+                        min_storage_deposit = min_storage_deposit.min(storage_deposit);
+                    }
+                }
+            }
+
+            // The consolidated output still needs a storage deposit
+            let reclaimable_storage_deposit = if dust_outputs.len() > 1 {
+                total_storage_deposit - min_storage_deposit
+            } else {
+                0
+            };
+
+            Response::DustReport(DustReportDto {
+                dust_outputs_count: dust_outputs.len(),
+                dust_outputs,
+                dust_amount: dust_amount.to_string(),
+                reclaimable_storage_deposit: reclaimable_storage_deposit.to_string(),
+            })
+        }
+        AccountMethod::GetAddressesBalances { addresses } => {
+            let addresses_balance = account.addresses_balance(addresses).await?;
+            Response::AddressesBalances(
+                addresses_balance
+                    .iter()
+                    .map(|(address, balance)| (address.clone(), AccountBalanceDto::from(balance)))
+                    .collect(),
+            )
+        }
         AccountMethod::PrepareOutput {
             params: options,
             transaction_options,
@@ -198,6 +548,105 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
         }
+        AccountMethod::PrepareSendWithExpiration { params, options } => {
+            let data = account
+                .prepare_send_with_expiration(
+                    params,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
+        AccountMethod::PrepareSendWithMetadata {
+            address,
+            amount,
+            metadata,
+            tag,
+            options,
+        } => {
+            let data = account
+                .prepare_send_with_metadata(
+                    address,
+                    u64::from_str(&amount).map_err(|_| iota_sdk::client::Error::InvalidAmount(amount.clone()))?,
+                    metadata.map(prefix_hex::decode).transpose()?,
+                    tag.map(prefix_hex::decode).transpose()?,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
+        AccountMethod::PrepareAliasStateTransition {
+            alias_id,
+            new_state_metadata,
+            new_sender,
+            options,
+        } => {
+            let data = account
+                .prepare_alias_state_transition(
+                    alias_id,
+                    new_state_metadata.map(prefix_hex::decode).transpose()?,
+                    new_sender,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
+        AccountMethod::PrepareGovernanceTransition {
+            alias_id,
+            new_state_controller,
+            new_governor,
+            options,
+        } => {
+            let data = account
+                .prepare_governance_transition(
+                    alias_id,
+                    new_state_controller,
+                    new_governor,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
+        AccountMethod::PrepareSendBatch { recipients, options } => {
+            let data = account
+                .prepare_send_batch(
+                    recipients,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
+        AccountMethod::PrepareSplitOutput {
+            amount_each,
+            count,
+            address,
+            options,
+        } => {
+            let data = account
+                .prepare_split_output(
+                    address,
+                    u64::from_str(&amount_each)
+                        .map_err(|_| iota_sdk::client::Error::InvalidAmount(amount_each.clone()))?,
+                    count,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
+        AccountMethod::PrepareSweep {
+            destination,
+            include_native_tokens,
+            options,
+        } => {
+            let data = account
+                .prepare_sweep(
+                    destination,
+                    include_native_tokens,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
         AccountMethod::PrepareTransaction { outputs, options } => {
             let token_supply = account.client().get_token_supply().await?;
             let data = account
@@ -211,6 +660,55 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
         }
+        AccountMethod::EstimateInputCount { outputs, options } => {
+            let token_supply = account.client().get_token_supply().await?;
+            let data = account
+                .prepare_transaction(
+                    outputs
+                        .iter()
+                        .map(|o| Ok(Output::try_from_dto(o, token_supply)?))
+                        .collect::<Result<Vec<Output>>>()?,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            // Nothing will be submitted, so the inputs locked during the preparation can be released again
+            account.unlock_inputs(&data.inputs_data).await?;
+
+            Response::Count(data.inputs_data.len())
+        }
+        AccountMethod::DryRunTransaction { outputs, options } => {
+            let token_supply = account.client().get_token_supply().await?;
+            let burn = options.as_ref().and_then(|options| options.burn.clone());
+            let data = account
+                .prepare_transaction(
+                    outputs
+                        .iter()
+                        .map(|o| Ok(Output::try_from_dto(o, token_supply)?))
+                        .collect::<Result<Vec<Output>>>()?,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            // Nothing will be submitted, so the inputs locked during the preparation can be released again
+            account.unlock_inputs(&data.inputs_data).await?;
+
+            let rent_structure = account.client().get_rent_structure().await?;
+            let TransactionEssence::Regular(essence) = &data.essence;
+            let required_storage_deposit = essence
+                .outputs()
+                .iter()
+                .map(|output| output.rent_cost(&rent_structure))
+                .sum::<u64>();
+
+            Response::TransactionDryRun(TransactionDryRunDto {
+                input_count: data.inputs_data.len(),
+                required_storage_deposit: required_storage_deposit.to_string(),
+                remainder: data
+                    .remainder
+                    .as_ref()
+                    .map(|remainder| OutputDto::from(&remainder.output)),
+                burn,
+            })
+        }
         AccountMethod::RetryTransactionUntilIncluded {
             transaction_id,
             interval,
@@ -221,7 +719,23 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::BlockId(block_id)
         }
+        AccountMethod::RetryAllPendingTransactions { interval, max_attempts } => {
+            let mut retried = RetriedTransactionsDto {
+                block_ids: Vec::new(),
+                failed: BTreeMap::new(),
+            };
+            for (transaction_id, result) in account.retry_all_pending_transactions(interval, max_attempts).await {
+                match result {
+                    Ok(block_id) => retried.block_ids.push(block_id),
+                    Err(error) => {
+                        retried.failed.insert(transaction_id, error);
+                    }
+                }
+            }
+            Response::RetriedTransactions(retried)
+        }
         AccountMethod::Sync { options } => Response::Balance(AccountBalanceDto::from(&account.sync(options).await?)),
+        AccountMethod::GetSyncStatus => Response::SyncStatus(account.sync_status().await),
         AccountMethod::SendAmount { params, options } => {
             let transaction = account
                 .send_amount(
@@ -240,6 +754,15 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::PrepareSendNativeTokens { params, options } => {
+            let data = account
+                .prepare_send_native_tokens(
+                    params,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
         AccountMethod::SendNft { params, options } => {
             let transaction = account
                 .send_nft(
@@ -249,6 +772,15 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::PrepareSendNft { params, options } => {
+            let data = account
+                .prepare_send_nft(
+                    params,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
         AccountMethod::SetAlias { alias } => {
             account.set_alias(&alias).await?;
             Response::Ok
@@ -257,6 +789,17 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             account.set_default_sync_options(options).await?;
             Response::Ok
         }
+        AccountMethod::GetDefaultSyncOptions => Response::SyncOptions(account.default_sync_options().await),
+        AccountMethod::SetDefaultRetryOptions { interval, max_attempts } => {
+            account
+                .set_default_retry_options(RetryOptions { interval, max_attempts })
+                .await?;
+            Response::Ok
+        }
+        AccountMethod::SetTransactionNote { transaction_id, note } => {
+            account.set_transaction_note(&transaction_id, &note).await?;
+            Response::Ok
+        }
         AccountMethod::SendOutputs { outputs, options } => {
             let token_supply = account.client().get_token_supply().await?;
             let transaction = account
@@ -270,6 +813,15 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::SignMessage {
+            address_index,
+            internal,
+            message,
+        } => {
+            let msg: Vec<u8> = prefix_hex::decode(message)?;
+            let signature = account.sign_message(address_index, internal, &msg).await?;
+            Response::Ed25519Signature(Ed25519SignatureDto::from(&signature))
+        }
         AccountMethod::SignTransactionEssence {
             prepared_transaction_data,
         } => {
@@ -291,10 +843,59 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let transaction = account.submit_and_store_transaction(signed_transaction_data).await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::SignedTransactionToBytes {
+            signed_transaction_data,
+        } => {
+            let signed_transaction_data = SignedTransactionData::try_from_dto(
+                &signed_transaction_data,
+                &account.client().get_protocol_parameters().await?,
+            )?;
+            Response::Raw(prefix_hex::encode(
+                signed_transaction_data.transaction_payload.pack_to_vec(),
+            ))
+        }
+        AccountMethod::SubmitRawTransaction { bytes } => {
+            // Unpacking with the protocol parameters also verifies that the network id matches the connected node
+            let transaction_payload = TransactionPayload::unpack_strict(
+                &prefix_hex::decode::<Vec<u8>>(&bytes)?[..],
+                &account.client().get_protocol_parameters().await?,
+            )?;
+            let TransactionEssence::Regular(essence) = transaction_payload.essence();
+            let input_ids = essence
+                .inputs()
+                .iter()
+                .filter_map(|input| match input {
+                    Input::Utxo(input) => Some(*input.output_id()),
+                    _ => None,
+                })
+                .collect();
+            let inputs_data = account
+                .client()
+                .get_outputs(input_ids)
+                .await?
+                .into_iter()
+                .map(|output| InputSigningData {
+                    output: output.output().clone(),
+                    output_metadata: output.metadata().clone(),
+                    chain: None,
+                })
+                .collect();
+            let transaction = account
+                .submit_and_store_transaction(SignedTransactionData {
+                    transaction_payload,
+                    inputs_data,
+                })
+                .await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
         AccountMethod::ClaimOutputs { output_ids_to_claim } => {
             let transaction = account.claim_outputs(output_ids_to_claim.to_vec()).await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::PrepareClaimOutputs { output_ids_to_claim } => {
+            let data = account.prepare_claim_outputs(output_ids_to_claim).await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
         #[cfg(feature = "participation")]
         AccountMethod::Vote { event_id, answers } => {
             let transaction = account.vote(event_id, answers).await?;
@@ -316,6 +917,23 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             Response::AccountParticipationOverview(overview)
         }
         #[cfg(feature = "participation")]
+        AccountMethod::GetActiveVotes => Response::ActiveVotes(
+            account
+                .get_active_votes()
+                .await?
+                .into_iter()
+                .map(|participation| (participation.event_id, participation.answers))
+                .collect(),
+        ),
+        #[cfg(feature = "participation")]
+        AccountMethod::GetParticipationRewards { event_ids } => Response::ParticipationRewards(
+            account
+                .get_participation_rewards(event_ids)
+                .await?
+                .into_iter()
+                .collect(),
+        ),
+        #[cfg(feature = "participation")]
         AccountMethod::IncreaseVotingPower { amount } => {
             let transaction = account
                 .increase_voting_power(
@@ -325,6 +943,15 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
         #[cfg(feature = "participation")]
+        AccountMethod::PrepareIncreaseVotingPower { amount } => {
+            let data = account
+                .prepare_increase_voting_power(
+                    u64::from_str(&amount).map_err(|_| iota_sdk::client::Error::InvalidAmount(amount.clone()))?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
+        #[cfg(feature = "participation")]
         AccountMethod::DecreaseVotingPower { amount } => {
             let transaction = account
                 .decrease_voting_power(
@@ -339,6 +966,11 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             Response::ParticipationEvents(events)
         }
         #[cfg(feature = "participation")]
+        AccountMethod::RegisterParticipationEventsFromNodes { nodes } => {
+            let events = account.register_participation_events_from_nodes(&nodes).await?;
+            Response::ParticipationEvents(events)
+        }
+        #[cfg(feature = "participation")]
         AccountMethod::DeregisterParticipationEvent { event_id } => {
             account.deregister_participation_event(&event_id).await?;
             Response::Ok
@@ -366,3 +998,42 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
     };
     Ok(response)
 }
+
+/// Returns all outputs, or only a page of them if an offset or limit is provided. Pages are ordered by output id so
+/// that paging through them doesn't skip or repeat outputs.
+fn outputs_response(outputs: Vec<OutputData>, offset: Option<usize>, limit: Option<usize>) -> Response {
+    if offset.is_none() && limit.is_none() {
+        return Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect());
+    }
+
+    Response::OutputsPage(outputs_page(outputs, offset, limit))
+}
+
+/// Returns a page of the outputs, ordered by output id.
+fn outputs_page(mut outputs: Vec<OutputData>, offset: Option<usize>, limit: Option<usize>) -> OutputsPageDto {
+    outputs.sort_unstable_by_key(|output_data| output_data.output_id);
+
+    let total = outputs.len();
+    let offset = offset.unwrap_or_default().min(total);
+    let end = limit.map_or(total, |limit| offset.saturating_add(limit).min(total));
+
+    OutputsPageDto {
+        outputs: outputs[offset..end].iter().map(OutputDataDto::from).collect(),
+        total,
+        next_offset: (end < total).then_some(end),
+    }
+}
+
+/// Converts transactions to their dto, ordered by timestamp.
+fn sorted_transactions<'a>(transactions: impl Iterator<Item = &'a Transaction>) -> Vec<TransactionDto> {
+    let mut transactions = transactions.collect::<Vec<_>>();
+    transactions.sort_by_key(|transaction| transaction.timestamp);
+    transactions.into_iter().map(TransactionDto::from).collect()
+}
+
+/// Parses IRC-30 native token metadata, returns `None` if the data isn't valid IRC-30 JSON.
+fn parse_irc_30_metadata(data: &[u8]) -> Option<Irc30Metadata> {
+    serde_json::from_slice::<Irc30Metadata>(data)
+        .ok()
+        .filter(|metadata| metadata.standard == Irc30Metadata::STANDARD)
+}
@@ -17,7 +17,7 @@ use crate::{
     },
     panic::{convert_async_panics, convert_panics},
     response::Response,
-    UtilsMethod,
+    Error, UtilsMethod,
 };
 
 pub trait CallMethod {
@@ -73,6 +73,32 @@ pub async fn call_wallet_method(wallet: &Wallet, method: WalletMethod) -> Respon
     response
 }
 
+/// Call wallet methods one after another, stopping at the first one that fails.
+/// The returned responses are in the same order as the methods. If a method fails, its error response, wrapped in
+/// [`BatchMethod`](crate::Error::BatchMethod) with the method's index, is the last one.
+pub async fn call_wallet_method_batch(wallet: &Wallet, methods: Vec<WalletMethod>) -> Vec<Response> {
+    let mut responses = Vec::with_capacity(methods.len());
+
+    for (index, method) in methods.into_iter().enumerate() {
+        match call_wallet_method(wallet, method).await {
+            Response::Error(error) => {
+                responses.push(Response::Error(Error::BatchMethod {
+                    index,
+                    error: Box::new(error),
+                }));
+                break;
+            }
+            response @ Response::Panic(_) => {
+                responses.push(response);
+                break;
+            }
+            response => responses.push(response),
+        }
+    }
+
+    responses
+}
+
 /// Call a utils method.
 pub fn call_utils_method(method: UtilsMethod) -> Response {
     log::debug!("Utils method: {method:?}");
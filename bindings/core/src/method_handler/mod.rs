@@ -9,7 +9,8 @@ mod utils;
 mod wallet;
 
 pub use call_method::{
-    call_client_method, call_secret_manager_method, call_utils_method, call_wallet_method, CallMethod,
+    call_client_method, call_secret_manager_method, call_utils_method, call_wallet_method, call_wallet_method_batch,
+    CallMethod,
 };
 #[cfg(feature = "mqtt")]
 pub use client::listen_mqtt;
@@ -1,19 +1,28 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crypto::hashes::{blake2b::Blake2b256, Digest};
 use iota_sdk::{
-    client::{hex_public_key_to_bech32_address, hex_to_bech32, verify_mnemonic, Client},
+    client::{
+        hex_public_key_to_bech32_address, hex_to_bech32, secret::mnemonic::MnemonicSecretManager, verify_mnemonic,
+        Client,
+    },
     types::block::{
-        address::{dto::AddressDto, Address, Ed25519Address},
-        output::{AliasId, FoundryId, NftId},
+        address::{dto::AddressDto, Address, AliasAddress, Ed25519Address},
+        output::{feature::MetadataFeature, AliasId, FoundryId, NftId, Output, OutputId, Rent, RentStructure, TokenId},
         payload::{transaction::TransactionEssence, TransactionPayload},
+        protocol::ProtocolParameters,
         signature::Ed25519Signature,
         Block,
     },
 };
 use zeroize::Zeroize;
 
-use crate::{method::UtilsMethod, response::Response, Result};
+use crate::{
+    method::UtilsMethod,
+    response::{Irc27Metadata, Irc30Metadata, OutputIdPartsDto, ParsedBech32AddressDto, Response},
+    Error, Result,
+};
 
 /// Call a utils method.
 pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response> {
@@ -27,8 +36,15 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
         UtilsMethod::HexPublicKeyToBech32Address { hex, bech32_hrp } => {
             Response::Bech32Address(hex_public_key_to_bech32_address(&hex, &bech32_hrp)?)
         }
+        UtilsMethod::ChangeBech32Hrp { address, new_hrp } => {
+            Response::Bech32Address(Address::try_from_bech32(address)?.to_bech32(new_hrp))
+        }
         UtilsMethod::ParseBech32Address { address } => {
-            Response::ParsedBech32Address(AddressDto::from(&Address::try_from_bech32(address)?))
+            let (hrp, address) = Address::try_from_bech32_with_hrp(address)?;
+            Response::ParsedBech32Address(ParsedBech32AddressDto {
+                hrp,
+                address: AddressDto::from(&address),
+            })
         }
         UtilsMethod::IsAddressValid { address } => Response::Bool(Address::is_valid_bech32(&address)),
         UtilsMethod::GenerateMnemonic => Response::GeneratedMnemonic(Client::generate_mnemonic()?),
@@ -41,10 +57,40 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
             let block = Block::try_from_dto_unverified(&block)?;
             Response::BlockId(block.id())
         }
+        UtilsMethod::ComputeBlockId {
+            block,
+            protocol_parameters,
+        } => {
+            let protocol_parameters = ProtocolParameters::try_from(protocol_parameters)?;
+            let block = Block::try_from_dto(&block, &protocol_parameters)?;
+            if block.protocol_version() != protocol_parameters.protocol_version() {
+                return Err(iota_sdk::types::block::Error::ProtocolVersionMismatch {
+                    expected: protocol_parameters.protocol_version(),
+                    actual: block.protocol_version(),
+                }
+                .into());
+            }
+            Response::BlockId(block.id())
+        }
         UtilsMethod::TransactionId { payload } => {
             let payload = TransactionPayload::try_from_dto_unverified(&payload)?;
             Response::TransactionId(payload.id())
         }
+        UtilsMethod::ComputeTransactionId {
+            payload,
+            protocol_parameters,
+        } => {
+            let protocol_parameters = ProtocolParameters::try_from(protocol_parameters)?;
+            let payload = TransactionPayload::try_from_dto(&payload, &protocol_parameters)?;
+            Response::TransactionId(payload.id())
+        }
+        UtilsMethod::ComputeOutputId { transaction_id, index } => {
+            Response::OutputId(OutputId::new(transaction_id, index)?)
+        }
+        UtilsMethod::OutputIdToTransactionIdAndIndex { output_id } => Response::OutputIdParts(OutputIdPartsDto {
+            transaction_id: *output_id.transaction_id(),
+            index: output_id.index(),
+        }),
         UtilsMethod::ComputeAliasId { output_id } => Response::AliasId(AliasId::from(&output_id)),
         UtilsMethod::ComputeNftId { output_id } => Response::NftId(NftId::from(&output_id)),
         UtilsMethod::ComputeFoundryId {
@@ -52,9 +98,21 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
             serial_number,
             token_scheme_kind,
         } => Response::FoundryId(FoundryId::build(&alias_address, serial_number, token_scheme_kind)),
+        UtilsMethod::ComputeTokenId {
+            alias_id,
+            serial_number,
+            token_scheme_kind,
+        } => {
+            let foundry_id = FoundryId::build(&AliasAddress::new(alias_id), serial_number, token_scheme_kind);
+            Response::TokenId(TokenId::from(foundry_id))
+        }
         UtilsMethod::HashTransactionEssence { essence } => Response::TransactionEssenceHash(prefix_hex::encode(
             TransactionEssence::try_from_dto_unverified(&essence)?.hash(),
         )),
+        UtilsMethod::Blake2b256 { data } => {
+            let data: Vec<u8> = prefix_hex::decode(data)?;
+            Response::Hash(prefix_hex::encode(Blake2b256::digest(data).as_slice()))
+        }
         UtilsMethod::VerifyEd25519Signature {
             signature,
             message,
@@ -62,14 +120,146 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
         } => {
             let signature = Ed25519Signature::try_from(&signature)?;
             let msg: Vec<u8> = prefix_hex::decode(message)?;
-            let address = Ed25519Address::try_from(&address)?;
-            Response::Bool(signature.is_valid(&msg, &address).is_ok())
+            let valid = match address {
+                Some(address) => {
+                    let address = Ed25519Address::try_from(&address)?;
+                    // Check the public key first, so malformed keys are returned as error
+                    signature.verify(&msg)? && signature.is_valid(&msg, &address).is_ok()
+                }
+                None => signature.verify(&msg)?,
+            };
+            Response::Bool(valid)
+        }
+        UtilsMethod::DeriveAddress {
+            mut seed_hex,
+            coin_type,
+            account_index,
+            internal,
+            address_index,
+            bech32_hrp,
+        } => {
+            let secret_manager = MnemonicSecretManager::try_from_hex_seed(&seed_hex)?;
+            seed_hex.zeroize();
+            let address = secret_manager.generate_address(coin_type, account_index, internal, address_index)?;
+            Response::Bech32Address(address.to_bech32(bech32_hrp))
         }
         UtilsMethod::VerifyMnemonic { mut mnemonic } => {
             verify_mnemonic(&mnemonic)?;
             mnemonic.zeroize();
             Response::Ok
         }
+        UtilsMethod::ConvertAmount { amount, from, to } => Response::Amount(Client::convert_amount(&amount, from, to)?),
+        UtilsMethod::ComputeStorageDeposit { output, rent_structure } => {
+            let output = Output::try_from_dto_unverified(&output)?;
+            let rent_structure = RentStructure::from(rent_structure);
+
+            Response::MinimumRequiredStorageDeposit(output.rent_cost(&rent_structure).to_string())
+        }
+        UtilsMethod::ValidateOutput {
+            output,
+            protocol_parameters,
+        } => {
+            let protocol_parameters = ProtocolParameters::try_from(protocol_parameters)?;
+            let output = Output::try_from_dto(&output, protocol_parameters.token_supply())?;
+            output.verify_storage_deposit(
+                *protocol_parameters.rent_structure(),
+                protocol_parameters.token_supply(),
+            )?;
+
+            Response::Ok
+        }
+        UtilsMethod::ParseIrc27Metadata { metadata } => {
+            let metadata = serde_json::from_slice::<Irc27Metadata>(&prefix_hex::decode::<Vec<u8>>(&metadata)?)?;
+            if metadata.standard != Irc27Metadata::STANDARD {
+                return Err(Error::InvalidMetadataStandard {
+                    expected: Irc27Metadata::STANDARD,
+                    found: metadata.standard,
+                });
+            }
+            Response::Irc27Metadata(metadata)
+        }
+        UtilsMethod::BuildIrc30Metadata {
+            name,
+            symbol,
+            decimals,
+            description,
+            url,
+            logo_url,
+        } => {
+            if name.is_empty() {
+                return Err(Error::InvalidMetadata("name can't be empty".to_string()));
+            }
+            if symbol.is_empty() {
+                return Err(Error::InvalidMetadata("symbol can't be empty".to_string()));
+            }
+            if decimals > Irc30Metadata::MAX_DECIMALS {
+                return Err(Error::InvalidMetadata(format!(
+                    "decimals can't be greater than {}",
+                    Irc30Metadata::MAX_DECIMALS
+                )));
+            }
+            let metadata = Irc30Metadata {
+                standard: Irc30Metadata::STANDARD.to_string(),
+                name,
+                description,
+                symbol,
+                decimals,
+                url,
+                logo_url,
+                logo: None,
+            };
+            Response::MetadataHex(metadata_hex(&metadata)?)
+        }
+        UtilsMethod::BuildIrc27Metadata {
+            nft_type,
+            uri,
+            name,
+            collection_name,
+            royalties,
+            issuer_name,
+            description,
+            attributes,
+        } => {
+            if !is_valid_mime_type(&nft_type) {
+                return Err(Error::InvalidMetadata(format!("invalid MIME type: {nft_type}")));
+            }
+            let metadata = Irc27Metadata {
+                standard: Irc27Metadata::STANDARD.to_string(),
+                version: Irc27Metadata::VERSION.to_string(),
+                kind: nft_type,
+                uri,
+                name,
+                collection_name,
+                royalties: royalties.unwrap_or_default(),
+                issuer_name,
+                description,
+                attributes: attributes.unwrap_or_default(),
+            };
+            Response::MetadataHex(metadata_hex(&metadata)?)
+        }
     };
     Ok(response)
 }
+
+/// Serializes metadata to JSON and hex encodes it, checking that it fits into a metadata feature.
+fn metadata_hex(metadata: &impl serde::Serialize) -> Result<String> {
+    let data = serde_json::to_vec(metadata)?;
+    // Validates the length of the data
+    let feature = MetadataFeature::new(data)?;
+    Ok(prefix_hex::encode(feature.data()))
+}
+
+/// Checks that a MIME type has the form `type/subtype`, both consisting of RFC 6838 name characters, optionally
+/// followed by parameters.
+fn is_valid_mime_type(mime_type: &str) -> bool {
+    let is_valid_name = |name: &str| {
+        name.starts_with(|c: char| c.is_ascii_alphanumeric())
+            && name.len() <= 127
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "!#$&-^_.+".contains(c))
+    };
+    let essence = mime_type.split(';').next().unwrap_or_default().trim();
+
+    matches!(essence.split_once('/'), Some((kind, subtype)) if is_valid_name(kind) && is_valid_name(subtype))
+}
@@ -1,14 +1,42 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::time::Duration;
+use std::{collections::BTreeMap, time::Duration};
 
-use iota_sdk::wallet::{message_interface::dtos::AccountDetailsDto, wallet::Wallet};
+use futures::Future;
+use iota_sdk::wallet::{
+    account::{
+        types::{AccountBalanceDto, AccountIdentifier},
+        Account,
+    },
+    message_interface::dtos::AccountDetailsDto,
+    wallet::Wallet,
+};
+#[cfg(feature = "events")]
+use iota_sdk::{
+    types::block::{
+        address::{Address, Ed25519Address},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputId, OutputMetadata},
+        payload::transaction::TransactionId,
+        BlockId,
+    },
+    wallet::{
+        account::types::{InclusionState, OutputData, OutputDataDto},
+        events::types::{
+            NewOutputEvent, SpentOutputEvent, SyncProgressEvent, TransactionInclusionEvent, TransactionProgressEvent,
+            WalletEvent, WalletEventType,
+        },
+    },
+};
 #[cfg(feature = "stronghold")]
 use zeroize::Zeroize;
 
 use super::account::call_account_method_internal;
-use crate::{method::WalletMethod, response::Response, Result};
+use crate::{
+    method::{AccountMethod, WalletMethod},
+    response::{AccountsBalancesDto, BackgroundSyncStatusDto, ClientConfigDto, ClientNodeDto, Response},
+    Error, Result,
+};
 
 /// Call a wallet method.
 pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletMethod) -> Result<Response> {
@@ -32,11 +60,34 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
                 Err(e) => return Err(e.into()),
             }
         }
+        WalletMethod::CreateWatchAccount { addresses, alias } => {
+            let account = wallet
+                .create_account()
+                .with_alias(alias)
+                .with_watch_only_addresses(addresses)
+                .finish()
+                .await?;
+            let account = account.details().await;
+            Response::Account(AccountDetailsDto::from(&*account))
+        }
         WalletMethod::GetAccount { account_id } => {
             let account = wallet.get_account(account_id.clone()).await?;
             let account = account.details().await;
             Response::Account(AccountDetailsDto::from(&*account))
         }
+        WalletMethod::GetAccountByIndex { index } => {
+            let account = wallet.get_account(AccountIdentifier::Index(index)).await?;
+            let account = account.details().await;
+            Response::Account(AccountDetailsDto::from(&*account))
+        }
+        WalletMethod::FindAccountForAddress { address } => {
+            let account = wallet
+                .find_account_for_address(&address)
+                .await?
+                .ok_or_else(|| iota_sdk::wallet::Error::AddressNotFoundInAccount(address.to_string()))?;
+            let account = account.details().await;
+            Response::Account(AccountDetailsDto::from(&*account))
+        }
         WalletMethod::GetAccountIndexes => {
             let accounts = wallet.get_accounts().await?;
             let mut account_indexes = Vec::new();
@@ -54,9 +105,55 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             }
             Response::Accounts(account_dtos)
         }
-        WalletMethod::CallAccountMethod { account_id, method } => {
+        WalletMethod::ImportAccounts { data } => {
+            let accounts = wallet.import_accounts(data).await?;
+            let mut account_dtos = Vec::new();
+            for account in accounts {
+                let account = account.details().await;
+                account_dtos.push(AccountDetailsDto::from(&*account));
+            }
+            Response::Accounts(account_dtos)
+        }
+        WalletMethod::SyncAllAccounts { options } => {
+            let mut synced = AccountsBalancesDto {
+                balances: BTreeMap::new(),
+                failed: BTreeMap::new(),
+            };
+            for (account_index, result) in wallet.sync_accounts(options).await {
+                match result {
+                    Ok(balance) => {
+                        synced.balances.insert(account_index, AccountBalanceDto::from(&balance));
+                    }
+                    Err(error) => {
+                        synced.failed.insert(account_index, error);
+                    }
+                }
+            }
+            Response::Balances(synced)
+        }
+        WalletMethod::SetDefaultSyncOptionsForAll { options } => {
+            wallet.set_default_sync_options(options).await?;
+            Response::Ok
+        }
+        WalletMethod::GetTotalBalance { sync } => {
+            let balance = if sync {
+                wallet.sync(None).await?
+            } else {
+                wallet.balance().await?
+            };
+            Response::Balance(AccountBalanceDto::from(&balance))
+        }
+        WalletMethod::CallAccountMethod {
+            account_id,
+            method,
+            timeout_ms,
+        } => {
             let account = wallet.get_account(account_id).await?;
-            call_account_method_internal(&account, method).await?
+            match timeout_ms {
+                Some(_) if !can_time_out(&method) => return Err(Error::TimeoutNotSupported),
+                Some(timeout_ms) => call_account_method_with_timeout(&account, method, timeout_ms).await?,
+                None => call_account_method_internal(&account, method).await?,
+            }
         }
         #[cfg(feature = "stronghold")]
         WalletMethod::Backup { destination, password } => {
@@ -64,6 +161,15 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             Response::Ok
         }
         #[cfg(feature = "stronghold")]
+        WalletMethod::BackupAccounts {
+            destination,
+            password,
+            account_ids,
+        } => {
+            wallet.backup_accounts(destination, password, account_ids).await?;
+            Response::Ok
+        }
+        #[cfg(feature = "stronghold")]
         WalletMethod::ChangeStrongholdPassword {
             mut current_password,
             mut new_password,
@@ -85,6 +191,11 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             let is_available = wallet.is_stronghold_password_available().await?;
             Response::Bool(is_available)
         }
+        #[cfg(feature = "stronghold")]
+        WalletMethod::GetStrongholdPasswordClearCountdown => {
+            let countdown = wallet.get_stronghold_password_clear_countdown().await?;
+            Response::Duration(countdown.map(|countdown| countdown.as_millis() as u64))
+        }
         WalletMethod::RecoverAccounts {
             account_start_index,
             account_gap_limit,
@@ -101,11 +212,19 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             }
             Response::Accounts(account_dtos)
         }
+        WalletMethod::RemoveAccount { account_id, force } => {
+            wallet.remove_account(account_id, force).await?;
+            Response::Ok
+        }
         WalletMethod::RemoveLatestAccount => {
             wallet.remove_latest_account().await?;
             Response::Ok
         }
         #[cfg(feature = "stronghold")]
+        WalletMethod::InspectBackup { source, password } => {
+            Response::BackupContents(wallet.inspect_backup(source, password).await?)
+        }
+        #[cfg(feature = "stronghold")]
         WalletMethod::RestoreBackup {
             source,
             password,
@@ -126,6 +245,10 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             wallet.set_client_options(*client_options).await?;
             Response::Ok
         }
+        WalletMethod::SetCoinType { coin_type, force } => {
+            wallet.set_coin_type(coin_type, force).await?;
+            Response::Ok
+        }
         #[cfg(feature = "ledger_nano")]
         WalletMethod::GetLedgerNanoStatus => {
             let ledger_nano_status = wallet.get_ledger_nano_status().await?;
@@ -153,6 +276,13 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             Response::Ok
         }
         #[cfg(feature = "stronghold")]
+        WalletMethod::SetStrongholdSnapshotPath { path, mut password } => {
+            let result = wallet.set_stronghold_snapshot_path(path, &password).await;
+            password.zeroize();
+            result?;
+            Response::Ok
+        }
+        #[cfg(feature = "stronghold")]
         WalletMethod::SetStrongholdPasswordClearInterval {
             interval_in_milliseconds,
         } => {
@@ -177,14 +307,66 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             wallet.stop_background_syncing().await?;
             Response::Ok
         }
+        WalletMethod::GetBackgroundSyncStatus => {
+            Response::BackgroundSyncStatus(BackgroundSyncStatusDto::from(&wallet.background_syncing_status().await))
+        }
+        WalletMethod::AbortSync => {
+            wallet.abort_sync().await?;
+            Response::Ok
+        }
         #[cfg(feature = "events")]
         WalletMethod::EmitTestEvent { event } => {
             wallet.emit_test_event(event.clone()).await;
             Response::Ok
         }
         #[cfg(feature = "events")]
-        WalletMethod::ClearListeners { event_types } => {
-            wallet.clear_listeners(event_types).await;
+        WalletMethod::EmitTestEventOfType { event_type } => {
+            let event = test_event_of_type(wallet, event_type).await?;
+            wallet.emit_test_event(event).await;
+            Response::Ok
+        }
+        #[cfg(feature = "events")]
+        WalletMethod::ClearListeners { event_types } => Response::Count(wallet.clear_listeners(event_types).await),
+        #[cfg(feature = "events")]
+        WalletMethod::GetActiveListeners => Response::ListenerTypes(wallet.active_listeners().await),
+        WalletMethod::GetNodeInfo => Response::NodeInfo(wallet.get_node_info().await?.node_info),
+        WalletMethod::GetNodesHealth => Response::NodesHealth(wallet.get_nodes_health().await),
+        WalletMethod::GetClientNodes => Response::Nodes(
+            wallet
+                .client_nodes()
+                .await
+                .into_iter()
+                .map(|node| ClientNodeDto {
+                    url: node.url,
+                    has_auth: node.auth.is_some(),
+                    disabled: node.disabled,
+                })
+                .collect(),
+        ),
+        WalletMethod::GetClientConfig => {
+            let client_options = wallet.client_options().await;
+            let node_manager_builder = client_options.node_manager_builder;
+            Response::ClientConfig(ClientConfigDto {
+                quorum: node_manager_builder.quorum,
+                min_quorum_size: node_manager_builder.min_quorum_size,
+                quorum_threshold: node_manager_builder.quorum_threshold,
+                ignore_node_health: node_manager_builder.ignore_node_health,
+                node_sync_interval: node_manager_builder.node_sync_interval,
+                api_timeout: client_options.api_timeout,
+                remote_pow_timeout: client_options.remote_pow_timeout,
+                bech32_hrp: client_options.network_info.protocol_parameters.bech32_hrp().to_string(),
+            })
+        }
+        WalletMethod::AddNode { url, auth, disabled } => {
+            wallet.add_node(url, auth, disabled).await?;
+            Response::Ok
+        }
+        WalletMethod::RemoveNode { url } => {
+            wallet.remove_node(url).await?;
+            Response::Ok
+        }
+        WalletMethod::SetPrimaryNode { url } => {
+            wallet.set_primary_node(url).await?;
             Response::Ok
         }
         WalletMethod::UpdateNodeAuth { url, auth } => {
@@ -194,3 +376,126 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
     };
     Ok(response)
 }
+
+/// Build a representative event of the given type, with a null address, ids and a basic output.
+#[cfg(feature = "events")]
+async fn test_event_of_type(wallet: &Wallet, event_type: WalletEventType) -> Result<WalletEvent> {
+    let address = Address::Ed25519(Ed25519Address::new([0; Ed25519Address::LENGTH]));
+    let output_data = |is_spent: bool| async move {
+        let output_id = OutputId::null();
+        crate::Result::Ok(OutputData {
+            output_id,
+            metadata: OutputMetadata::new(BlockId::null(), output_id, is_spent, None, None, None, 0, 0, 0),
+            output: BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish_output(wallet.client().get_token_supply().await?)?,
+            is_spent,
+            address,
+            network_id: wallet.client().get_network_id().await?,
+            remainder: false,
+            chain: None,
+        })
+    };
+
+    // Exhaustive, so a new event type can't be added without a test event for it
+    Ok(match event_type {
+        WalletEventType::ConsolidationRequired => WalletEvent::ConsolidationRequired,
+        #[cfg(feature = "ledger_nano")]
+        WalletEventType::LedgerAddressGeneration => {
+            WalletEvent::LedgerAddressGeneration(iota_sdk::wallet::events::types::AddressData {
+                address: address.to_bech32(wallet.client().get_bech32_hrp().await?),
+            })
+        }
+        WalletEventType::NewOutput => WalletEvent::NewOutput(Box::new(NewOutputEvent {
+            output: OutputDataDto::from(&output_data(false).await?),
+            transaction: None,
+            transaction_inputs: None,
+        })),
+        WalletEventType::SpentOutput => WalletEvent::SpentOutput(Box::new(SpentOutputEvent {
+            output: OutputDataDto::from(&output_data(true).await?),
+        })),
+        WalletEventType::SyncProgress => WalletEvent::SyncProgress(SyncProgressEvent {
+            addresses_scanned: 1,
+            total_addresses: 1,
+            outputs_found: 0,
+        }),
+        WalletEventType::TransactionInclusion => WalletEvent::TransactionInclusion(TransactionInclusionEvent {
+            transaction_id: TransactionId::null(),
+            inclusion_state: InclusionState::Confirmed,
+        }),
+        WalletEventType::TransactionProgress => {
+            WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs)
+        }
+    })
+}
+
+// A sync isn't dropped when the timeout fires, because it could be in the middle of updating the account, instead it's
+// aborted and awaited until it stopped at the next address batch
+async fn call_account_method_with_timeout(
+    account: &Account,
+    method: AccountMethod,
+    timeout_ms: u64,
+) -> Result<Response> {
+    let is_sync = matches!(method, AccountMethod::Sync { .. });
+    let mut call = Box::pin(call_account_method_internal(account, method));
+    if let Some(response) = with_timeout(Duration::from_millis(timeout_ms), &mut call).await {
+        return response;
+    }
+    if !is_sync {
+        return Err(Error::RequestTimeout(timeout_ms));
+    }
+    let (response, abort_result) = futures::future::join(call, account.abort_sync()).await;
+    abort_result?;
+    match response {
+        Err(Error::Wallet(iota_sdk::wallet::Error::SyncAborted)) => Err(Error::RequestTimeout(timeout_ms)),
+        // The sync finished before it could be aborted
+        response => response,
+    }
+}
+
+/// Returns `None` if the future didn't complete within the timeout.
+async fn with_timeout<T>(timeout: Duration, future: impl Future<Output = T>) -> Option<T> {
+    #[cfg(not(target_family = "wasm"))]
+    {
+        tokio::time::timeout(timeout, future).await.ok()
+    }
+    #[cfg(target_family = "wasm")]
+    {
+        match futures::future::select(
+            Box::pin(future),
+            gloo_timers::future::TimeoutFuture::new(u32::try_from(timeout.as_millis()).unwrap_or(u32::MAX)),
+        )
+        .await
+        {
+            futures::future::Either::Left((output, _)) => Some(output),
+            futures::future::Either::Right(_) => None,
+        }
+    }
+}
+
+// Only methods that don't lock, sign or submit outputs can be cancelled without leaving the account in an inconsistent
+// state, syncing is aborted instead, see `call_account_method_with_timeout()`
+fn can_time_out(method: &AccountMethod) -> bool {
+    match method {
+        AccountMethod::Sync { .. }
+        | AccountMethod::GetSyncStatus
+        | AccountMethod::GetBalance
+        | AccountMethod::GetSpendableAtTime { .. }
+        | AccountMethod::GetAddressesBalances { .. }
+        | AccountMethod::GetDustReport { .. }
+        | AccountMethod::GetFoundryOutput { .. }
+        | AccountMethod::GetNativeTokensMetadata { .. }
+        | AccountMethod::GetOutputsWithAdditionalUnlockConditions { .. }
+        | AccountMethod::GetClaimableOutputsSummary { .. }
+        | AccountMethod::GetTransactionInclusionState { .. }
+        | AccountMethod::GetPendingTransactionsStatus
+        | AccountMethod::MinimumRequiredStorageDeposit { .. } => true,
+        #[cfg(feature = "participation")]
+        AccountMethod::GetVotingPower
+        | AccountMethod::GetParticipationOverview { .. }
+        | AccountMethod::GetActiveVotes
+        | AccountMethod::GetParticipationRewards { .. }
+        | AccountMethod::GetParticipationEventStatus { .. } => true,
+        _ => false,
+    }
+}
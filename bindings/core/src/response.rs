@@ -3,13 +3,18 @@
 
 #[cfg(not(target_family = "wasm"))]
 use std::collections::HashSet;
+use std::{collections::BTreeMap, time::Duration};
 
 use derivative::Derivative;
 #[cfg(feature = "ledger_nano")]
 use iota_sdk::client::secret::LedgerNanoStatus;
+#[cfg(feature = "events")]
+use iota_sdk::wallet::events::types::WalletEventType;
+#[cfg(feature = "stronghold")]
+use iota_sdk::wallet::BackupContents;
 use iota_sdk::{
     client::{
-        api::{PreparedTransactionDataDto, SignedTransactionDataDto},
+        api::{input_selection::BurnDto, PreparedTransactionDataDto, SignedTransactionDataDto},
         node_manager::node::Node,
         NetworkInfoDto, NodeInfoWrapper,
     },
@@ -25,11 +30,11 @@ use iota_sdk::{
             plugins::indexer::OutputIdsResponse,
         },
         block::{
-            address::dto::AddressDto,
+            address::{dto::AddressDto, Bech32Address},
             input::dto::UtxoInputDto,
             output::{
                 dto::{OutputDto, OutputMetadataDto},
-                AliasId, FoundryId, NftId, OutputId,
+                AliasId, FoundryId, NftId, OutputId, TokenId,
             },
             payload::{
                 dto::{MilestonePayloadDto, PayloadDto},
@@ -43,13 +48,15 @@ use iota_sdk::{
     },
     wallet::{
         account::{
-            types::{AccountAddress, AccountBalanceDto, AddressWithUnspentOutputs, TransactionDto},
-            MintTokenTransactionDto, OutputDataDto,
+            types::{AccountAddress, AccountBalanceDto, AddressWithUnspentOutputs, InclusionState, TransactionDto},
+            MintTokenTransactionDto, OutputDataDto, SyncOptions, SyncStatus,
         },
         message_interface::dtos::AccountDetailsDto,
+        BackgroundSyncStatus, Error as WalletError,
     },
+    Url, U256,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 #[cfg(feature = "participation")]
 use {
     iota_sdk::types::api::plugins::participation::types::{ParticipationEventId, ParticipationEventStatus},
@@ -100,6 +107,7 @@ pub enum Response {
     SignatureUnlock(UnlockDto),
     /// Response for:
     /// - [`SignEd25519`](crate::method::SecretManagerMethod::SignEd25519)
+    /// - [`SignMessage`](crate::method::AccountMethod::SignMessage)
     Ed25519Signature(Ed25519SignatureDto),
     /// Response for:
     /// - [`UnhealthyNodes`](crate::method::ClientMethod::UnhealthyNodes)
@@ -107,6 +115,7 @@ pub enum Response {
     UnhealthyNodes(HashSet<Node>),
     /// Response for:
     /// - [`GetNodeInfo`](crate::method::ClientMethod::GetNodeInfo)
+    /// - [`GetNodeInfo`](crate::method::WalletMethod::GetNodeInfo)
     NodeInfo(NodeInfo),
     /// Response for:
     /// - [`GetInfo`](crate::method::ClientMethod::GetInfo)
@@ -166,8 +175,12 @@ pub enum Response {
     /// - [`AliasOutputId`](crate::method::ClientMethod::AliasOutputId)
     /// - [`NftOutputId`](crate::method::ClientMethod::NftOutputId)
     /// - [`FoundryOutputId`](crate::method::ClientMethod::FoundryOutputId)
+    /// - [`ComputeOutputId`](crate::method::UtilsMethod::ComputeOutputId)
     OutputId(OutputId),
     /// Response for:
+    /// - [`OutputIdToTransactionIdAndIndex`](crate::method::UtilsMethod::OutputIdToTransactionIdAndIndex)
+    OutputIdParts(OutputIdPartsDto),
+    /// Response for:
     /// - [`BasicOutputIds`](crate::method::ClientMethod::BasicOutputIds)
     /// - [`AliasOutputIds`](crate::method::ClientMethod::AliasOutputIds)
     /// - [`NftOutputIds`](crate::method::ClientMethod::NftOutputIds)
@@ -198,12 +211,13 @@ pub enum Response {
     Bech32ToHex(String),
     /// Response for:
     /// - [`ParseBech32Address`](crate::method::UtilsMethod::ParseBech32Address)
-    ParsedBech32Address(AddressDto),
+    ParsedBech32Address(ParsedBech32AddressDto),
     /// Response for:
     /// - [`MnemonicToHexSeed`](crate::method::UtilsMethod::MnemonicToHexSeed)
     MnemonicHexSeed(#[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))] String),
     /// Response for:
     /// - [`TransactionId`](crate::method::UtilsMethod::TransactionId)
+    /// - [`ComputeTransactionId`](crate::method::UtilsMethod::ComputeTransactionId)
     TransactionId(TransactionId),
     /// Response for:
     /// - [`ComputeAliasId`](crate::method::UtilsMethod::ComputeAliasId)
@@ -215,8 +229,24 @@ pub enum Response {
     /// - [`ComputeFoundryId`](crate::method::UtilsMethod::ComputeFoundryId)
     FoundryId(FoundryId),
     /// Response for:
+    /// - [`ComputeTokenId`](crate::method::UtilsMethod::ComputeTokenId)
+    TokenId(TokenId),
+    /// Response for:
     /// - [`HashTransactionEssence`](crate::method::UtilsMethod::HashTransactionEssence)
     TransactionEssenceHash(String),
+    /// Response for:
+    /// - [`Blake2b256`](crate::method::UtilsMethod::Blake2b256)
+    Hash(String),
+    /// Response for:
+    /// - [`ConvertAmount`](crate::method::UtilsMethod::ConvertAmount)
+    Amount(String),
+    /// Response for:
+    /// - [`ParseIrc27Metadata`](crate::method::UtilsMethod::ParseIrc27Metadata)
+    Irc27Metadata(Irc27Metadata),
+    /// Response for:
+    /// - [`BuildIrc27Metadata`](crate::method::UtilsMethod::BuildIrc27Metadata)
+    /// - [`BuildIrc30Metadata`](crate::method::UtilsMethod::BuildIrc30Metadata)
+    MetadataHex(String),
     /// Response for [`GetNodeInfo`](crate::method::ClientMethod::GetNodeInfo)
     NodeInfoWrapper(NodeInfoWrapper),
     /// Response for [`Bech32ToHex`](crate::method::UtilsMethod::Bech32ToHex)
@@ -238,6 +268,8 @@ pub enum Response {
     /// - [`HexPublicKeyToBech32Address`](crate::method::ClientMethod::HexPublicKeyToBech32Address)
     /// - [`HexToBech32`](crate::method::ClientMethod::HexToBech32)
     /// - [`NftIdToBech32`](crate::method::ClientMethod::NftIdToBech32)
+    /// - [`DeriveAddress`](crate::method::UtilsMethod::DeriveAddress)
+    /// - [`ChangeBech32Hrp`](crate::method::UtilsMethod::ChangeBech32Hrp)
     Bech32Address(String),
     /// - [`Faucet`](crate::method::UtilsMethod::Faucet)
     Faucet(String),
@@ -253,6 +285,7 @@ pub enum Response {
     /// - [`BlockId`](crate::method::UtilsMethod::BlockId)
     /// - [`PostBlock`](crate::method::ClientMethod::PostBlock)
     /// - [`PostBlockRaw`](crate::method::ClientMethod::PostBlockRaw)
+    /// - [`ComputeBlockId`](crate::method::UtilsMethod::ComputeBlockId)
     /// - [`RetryTransactionUntilIncluded`](crate::method::AccountMethod::RetryTransactionUntilIncluded)
     BlockId(BlockId),
     /// Response for
@@ -261,20 +294,52 @@ pub enum Response {
     /// - [`VerifyEd25519Signature`](crate::method::UtilsMethod::VerifyEd25519Signature)
     /// - [`GetHealth`](crate::method::ClientMethod::GetHealth)
     /// - [`IsAddressValid`](crate::method::UtilsMethod::IsAddressValid)
+    /// - [`IsAddressMine`](crate::method::AccountMethod::IsAddressMine)
     Bool(bool),
+    /// Duration in milliseconds.
+    /// Response for
+    /// - [`GetStrongholdPasswordClearCountdown`](crate::method::WalletMethod::GetStrongholdPasswordClearCountdown)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    Duration(Option<u64>),
+    /// Response for
+    /// - [`InspectBackup`](crate::method::WalletMethod::InspectBackup)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    BackupContents(BackupContents),
+    /// Response for
+    /// - [`ClearListeners`](crate::method::WalletMethod::ClearListeners)
+    /// - [`EstimateInputCount`](crate::method::AccountMethod::EstimateInputCount)
+    Count(usize),
+    /// Response for [`GetBackgroundSyncStatus`](crate::method::WalletMethod::GetBackgroundSyncStatus)
+    BackgroundSyncStatus(BackgroundSyncStatusDto),
+    /// Response for [`GetActiveListeners`](crate::method::WalletMethod::GetActiveListeners)
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    ListenerTypes(Vec<(WalletEventType, usize)>),
     /// Response for
     /// - [`Backup`](crate::method::WalletMethod::Backup),
+    /// - [`BackupAccounts`](crate::method::WalletMethod::BackupAccounts),
     /// - [`ClearStrongholdPassword`](crate::method::WalletMethod::ClearStrongholdPassword),
     /// - [`DeregisterParticipationEvent`](crate::method::AccountMethod::DeregisterParticipationEvent),
+    /// - [`RemoveAccount`](crate::method::WalletMethod::RemoveAccount),
     /// - [`RestoreBackup`](crate::method::WalletMethod::RestoreBackup),
     /// - [`SetClientOptions`](crate::method::WalletMethod::SetClientOptions),
+    /// - [`SetCoinType`](crate::method::WalletMethod::SetCoinType),
+    /// - [`SetDefaultSyncOptionsForAll`](crate::method::WalletMethod::SetDefaultSyncOptionsForAll),
     /// - [`SetStrongholdPassword`](crate::method::WalletMethod::SetStrongholdPassword),
     /// - [`SetStrongholdPasswordClearInterval`](crate::method::WalletMethod::SetStrongholdPasswordClearInterval),
+    /// - [`SetTransactionNote`](crate::method::AccountMethod::SetTransactionNote),
     /// - [`StoreMnemonic`](crate::method::WalletMethod::StoreMnemonic),
     /// - [`StartBackgroundSync`](crate::method::WalletMethod::StartBackgroundSync),
     /// - [`StopBackgroundSync`](crate::method::WalletMethod::StopBackgroundSync),
+    /// - [`ValidateOutput`](crate::method::UtilsMethod::ValidateOutput),
+    /// - [`AbortSync`](crate::method::WalletMethod::AbortSync),
     /// - [`EmitTestEvent`](crate::method::WalletMethod::EmitTestEvent),
-    /// - [`ClearListeners`](crate::method::WalletMethod::ClearListeners)
+    /// - [`EmitTestEventOfType`](crate::method::WalletMethod::EmitTestEventOfType),
+    /// - [`AddNode`](crate::method::WalletMethod::AddNode),
+    /// - [`RemoveNode`](crate::method::WalletMethod::RemoveNode),
+    /// - [`SetPrimaryNode`](crate::method::WalletMethod::SetPrimaryNode),
     /// - [`StoreMnemonic`](crate::method::WalletMethod::StoreMnemonic)
     Ok,
     /// Response for any method that returns an error.
@@ -285,38 +350,109 @@ pub enum Response {
     // wallet responses
     /// Response for
     /// - [`CreateAccount`](crate::method::WalletMethod::CreateAccount),
-    /// - [`GetAccount`](crate::method::WalletMethod::GetAccount)
+    /// - [`CreateWatchAccount`](crate::method::WalletMethod::CreateWatchAccount),
+    /// - [`GetAccount`](crate::method::WalletMethod::GetAccount),
+    /// - [`GetAccountByIndex`](crate::method::WalletMethod::GetAccountByIndex),
+    /// - [`FindAccountForAddress`](crate::method::WalletMethod::FindAccountForAddress)
     Account(AccountDetailsDto),
     /// Response for [`GetAccountIndexes`](crate::method::WalletMethod::GetAccountIndexes)
     AccountIndexes(Vec<u32>),
-    /// Response for [`GetAccounts`](crate::method::WalletMethod::GetAccounts)
+    /// Response for:
+    /// - [`GetAccounts`](crate::method::WalletMethod::GetAccounts)
+    /// - [`ImportAccounts`](crate::method::WalletMethod::ImportAccounts)
     Accounts(Vec<AccountDetailsDto>),
-    /// Response for [`Addresses`](crate::method::AccountMethod::Addresses)
+    /// Response for:
+    /// - [`Addresses`](crate::method::AccountMethod::Addresses)
+    /// - [`GetUnusedAddresses`](crate::method::AccountMethod::GetUnusedAddresses)
     Addresses(Vec<AccountAddress>),
+    /// Response for:
+    /// - [`GetAddressesWithPaths`](crate::method::AccountMethod::GetAddressesWithPaths)
+    AddressesWithPaths(Vec<(Bech32Address, Bip44PathDto)>),
     /// Response for
     /// - [`AddressesWithUnspentOutputs`](crate::method::AccountMethod::AddressesWithUnspentOutputs)
     AddressesWithUnspentOutputs(Vec<AddressWithUnspentOutputs>),
     /// Response for
     /// - [`MinimumRequiredStorageDeposit`](crate::method::AccountMethod::MinimumRequiredStorageDeposit)
+    /// - [`ComputeStorageDeposit`](crate::method::UtilsMethod::ComputeStorageDeposit)
     MinimumRequiredStorageDeposit(String),
     /// Response for
     /// - [`GetOutputsWithAdditionalUnlockConditions`](crate::method::AccountMethod::GetOutputsWithAdditionalUnlockConditions)
     OutputIds(Vec<OutputId>),
+    /// Response for
+    /// - [`GetClaimableOutputsSummary`](crate::method::AccountMethod::GetClaimableOutputsSummary)
+    ClaimableSummary(ClaimableOutputsSummaryDto),
+    /// Response for
+    /// - [`GetDustReport`](crate::method::AccountMethod::GetDustReport)
+    DustReport(DustReportDto),
     /// Response for [`GetOutput`](crate::method::AccountMethod::GetOutput)
     OutputData(Option<Box<OutputDataDto>>),
     /// Response for
+    /// - [`GetOutputs`](crate::method::AccountMethod::GetOutputs),
     /// - [`Outputs`](crate::method::AccountMethod::Outputs),
-    /// - [`UnspentOutputs`](crate::method::AccountMethod::UnspentOutputs)
+    /// - [`UnspentOutputs`](crate::method::AccountMethod::UnspentOutputs),
+    /// - [`GetNftOutputs`](crate::method::AccountMethod::GetNftOutputs)
     OutputsData(Vec<OutputDataDto>),
     /// Response for
+    /// - [`Outputs`](crate::method::AccountMethod::Outputs),
+    /// - [`UnspentOutputs`](crate::method::AccountMethod::UnspentOutputs)
+    /// if an offset or limit is provided
+    /// - [`GetSpentOutputs`](crate::method::AccountMethod::GetSpentOutputs)
+    OutputsPage(OutputsPageDto),
+    /// Response for
+    /// - [`ExportHistory`](crate::method::AccountMethod::ExportHistory)
+    HistoryExport(HistoryExportDto),
+    /// Response for
+    /// - [`RetryAllPendingTransactions`](crate::method::AccountMethod::RetryAllPendingTransactions)
+    RetriedTransactions(RetriedTransactionsDto),
+    /// Response for
+    /// - [`GetFoundryOutputs`](crate::method::AccountMethod::GetFoundryOutputs)
+    FoundryOutputsData(Vec<FoundryOutputDataDto>),
+    /// Timelocked outputs with the unix timestamp at which they unlock.
+    /// Response for
+    /// - [`GetTimelockedOutputs`](crate::method::AccountMethod::GetTimelockedOutputs)
+    TimelockedOutputs(Vec<(OutputDataDto, u32)>),
+    /// Response for
+    /// - [`GetNativeTokensMetadata`](crate::method::AccountMethod::GetNativeTokensMetadata)
+    NativeTokensMetadata(Vec<(TokenId, Option<Irc30Metadata>)>),
+    /// Response for
+    /// - [`PrepareAliasStateTransition`](crate::method::AccountMethod::PrepareAliasStateTransition),
+    /// - [`PrepareBurn`](crate::method::AccountMethod::PrepareBurn),
+    /// - [`PrepareClaimOutputs`](crate::method::AccountMethod::PrepareClaimOutputs),
+    /// - [`PrepareConsolidateOutputs`](crate::method::AccountMethod::PrepareConsolidateOutputs),
+    /// - [`PrepareCreateAliasOutput`](crate::method::AccountMethod::PrepareCreateAliasOutput),
+    /// - [`PrepareGovernanceTransition`](crate::method::AccountMethod::PrepareGovernanceTransition),
+    /// - [`PrepareIncreaseVotingPower`](crate::method::AccountMethod::PrepareIncreaseVotingPower),
+    /// - [`PrepareMintNativeToken`](crate::method::AccountMethod::PrepareMintNativeToken),
+    /// - [`PrepareMintNfts`](crate::method::AccountMethod::PrepareMintNfts),
     /// - [`PrepareSendAmount`](crate::method::AccountMethod::PrepareSendAmount),
+    /// - [`PrepareSendBatch`](crate::method::AccountMethod::PrepareSendBatch),
+    /// - [`PrepareSendNativeTokens`](crate::method::AccountMethod::PrepareSendNativeTokens),
+    /// - [`PrepareSendNft`](crate::method::AccountMethod::PrepareSendNft),
+    /// - [`PrepareSendWithExpiration`](crate::method::AccountMethod::PrepareSendWithExpiration),
+    /// - [`PrepareSendWithMetadata`](crate::method::AccountMethod::PrepareSendWithMetadata),
+    /// - [`PrepareSplitOutput`](crate::method::AccountMethod::PrepareSplitOutput),
+    /// - [`PrepareSweep`](crate::method::AccountMethod::PrepareSweep),
     /// - [`PrepareTransaction`](crate::method::AccountMethod::PrepareTransaction)
     PreparedTransaction(PreparedTransactionDataDto),
     /// Response for
+    /// - [`DryRunTransaction`](crate::method::AccountMethod::DryRunTransaction)
+    TransactionDryRun(TransactionDryRunDto),
+    /// Response for
     /// - [`GetTransaction`](crate::method::AccountMethod::GetTransaction),
-    /// - [`GetIncomingTransaction`](crate::method::AccountMethod::GetIncomingTransaction)
+    /// - [`GetIncomingTransaction`](crate::method::AccountMethod::GetIncomingTransaction),
+    /// - [`GetOutputTransaction`](crate::method::AccountMethod::GetOutputTransaction)
     Transaction(Option<Box<TransactionDto>>),
     /// Response for
+    /// - [`GetTransactionNote`](crate::method::AccountMethod::GetTransactionNote)
+    TransactionNote(Option<String>),
+    /// Response for
+    /// - [`GetTransactionInclusionState`](crate::method::AccountMethod::GetTransactionInclusionState)
+    InclusionState(InclusionState),
+    /// Inclusion states of the pending transactions, with the number of times they got reattached.
+    /// Response for
+    /// - [`GetPendingTransactionsStatus`](crate::method::AccountMethod::GetPendingTransactionsStatus)
+    PendingStatuses(Vec<(TransactionId, InclusionState, u32)>),
+    /// Response for
     /// - [`Transactions`](crate::method::AccountMethod::Transactions),
     /// - [`PendingTransactions`](crate::method::AccountMethod::PendingTransactions),
     /// - [`IncomingTransactions`](crate::method::AccountMethod::IncomingTransactions)
@@ -325,19 +461,45 @@ pub enum Response {
     /// - [`SignTransactionEssence`](crate::method::AccountMethod::SignTransactionEssence)
     /// - [`SubmitAndStoreTransaction`](crate::method::AccountMethod::SubmitAndStoreTransaction)
     SignedTransactionData(SignedTransactionDataDto),
+    /// Hex encoded bytes.
+    /// Response for
+    /// - [`SignedTransactionToBytes`](crate::method::AccountMethod::SignedTransactionToBytes)
+    Raw(String),
     /// GenerateAddress response.
-    /// Response for [`GenerateAddresses`](crate::method::AccountMethod::GenerateAddresses)
+    /// Response for:
+    /// - [`GenerateAddresses`](crate::method::AccountMethod::GenerateAddresses)
+    /// - [`GetNextUnusedAddress`](crate::method::AccountMethod::GetNextUnusedAddress)
     GeneratedAddress(Vec<AccountAddress>),
     /// Response for
     /// - [`GetBalance`](crate::method::AccountMethod::GetBalance),
-    /// - [`Sync`](crate::method::AccountMethod::Sync)
+    /// - [`GetSpendableAtTime`](crate::method::AccountMethod::GetSpendableAtTime),
+    /// - [`Sync`](crate::method::AccountMethod::Sync),
+    /// - [`GetTotalBalance`](crate::method::WalletMethod::GetTotalBalance)
     Balance(AccountBalanceDto),
+    /// Response for [`SyncAllAccounts`](crate::method::WalletMethod::SyncAllAccounts)
+    Balances(AccountsBalancesDto),
+    /// Response for [`GetDefaultSyncOptions`](crate::method::AccountMethod::GetDefaultSyncOptions)
+    SyncOptions(SyncOptions),
+    /// Response for [`GetSyncStatus`](crate::method::AccountMethod::GetSyncStatus)
+    SyncStatus(SyncStatus),
+    /// Node url, health flag and confirmed milestone index for each node, the index is `None` if the node couldn't be
+    /// reached.
+    /// Response for [`GetNodesHealth`](crate::method::WalletMethod::GetNodesHealth)
+    NodesHealth(Vec<(Url, bool, Option<u32>)>),
+    /// Response for [`GetClientNodes`](crate::method::WalletMethod::GetClientNodes)
+    Nodes(Vec<ClientNodeDto>),
+    /// Response for [`GetClientConfig`](crate::method::WalletMethod::GetClientConfig)
+    ClientConfig(ClientConfigDto),
+    /// Response for
+    /// - [`GetAddressesBalances`](crate::method::AccountMethod::GetAddressesBalances)
+    AddressesBalances(Vec<(Bech32Address, AccountBalanceDto)>),
     /// Response for
     /// - [`ConsolidateOutputs`](crate::method::AccountMethod::ConsolidateOutputs)
     /// - [`ClaimOutputs`](crate::method::AccountMethod::ClaimOutputs)
     /// - [`CreateAliasOutput`](crate::method::AccountMethod::CreateAliasOutput)
     /// - [`SendAmount`](crate::method::AccountMethod::SendAmount),
     /// - [`MintNfts`](crate::method::AccountMethod::MintNfts),
+    /// - [`MintNftCollection`](crate::method::AccountMethod::MintNftCollection),
     /// - [`SendAmount`](crate::method::AccountMethod::SendAmount),
     /// - [`SendNativeTokens`](crate::method::AccountMethod::SendNativeTokens),
     /// - [`SendNft`](crate::method::AccountMethod::SendNft),
@@ -350,6 +512,8 @@ pub enum Response {
     SentTransaction(TransactionDto),
     /// Response for
     /// - [`MintNativeToken`](crate::method::AccountMethod::MintNativeToken),
+    /// - [`IncreaseNativeTokenSupply`](crate::method::AccountMethod::IncreaseNativeTokenSupply),
+    /// - [`DecreaseNativeTokenSupply`](crate::method::AccountMethod::DecreaseNativeTokenSupply)
     MintTokenTransaction(MintTokenTransactionDto),
     /// Response for
     /// - [`GetParticipationEvent`](crate::method::AccountMethod::GetParticipationEvent)
@@ -369,6 +533,7 @@ pub enum Response {
     ParticipationEventStatus(ParticipationEventStatus),
     /// Response for
     /// - [`GetParticipationEvents`](crate::method::AccountMethod::GetParticipationEvents)
+    /// - [`RegisterParticipationEventsFromNodes`](crate::method::AccountMethod::RegisterParticipationEventsFromNodes)
     #[cfg(feature = "participation")]
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     ParticipationEvents(HashMap<ParticipationEventId, ParticipationEventWithNodes>),
@@ -382,4 +547,285 @@ pub enum Response {
     #[cfg(feature = "participation")]
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     AccountParticipationOverview(AccountParticipationOverview),
+    /// Response for
+    /// - [`GetActiveVotes`](crate::method::AccountMethod::GetActiveVotes)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    ActiveVotes(Vec<(ParticipationEventId, Vec<u8>)>),
+    /// Response for
+    /// - [`GetParticipationRewards`](crate::method::AccountMethod::GetParticipationRewards)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    ParticipationRewards(Vec<(ParticipationEventId, u64)>),
+}
+
+/// Summary of a transaction that went through input selection, but was neither signed nor submitted.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionDryRunDto {
+    /// The number of inputs selected for the transaction.
+    pub input_count: usize,
+    /// The storage deposit required by all outputs of the transaction, including the remainder.
+    pub required_storage_deposit: String,
+    /// The remainder output, if one is needed.
+    pub remainder: Option<OutputDto>,
+    /// What the transaction burns, if anything.
+    pub burn: Option<BurnDto>,
+}
+
+/// The data of a foundry output stored in the account, with the id of the token it controls.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FoundryOutputDataDto {
+    /// The id of the token controlled by the foundry.
+    pub token_id: TokenId,
+    /// The output data.
+    #[serde(flatten)]
+    pub output_data: OutputDataDto,
+}
+
+/// Summary of what would be received by claiming outputs.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaimableOutputsSummaryDto {
+    /// The amount of base coins that would be received, storage deposits that need to be returned are excluded.
+    pub amount: String,
+    /// The number of claimable basic outputs.
+    pub basic_outputs: usize,
+    /// The number of claimable NFT outputs.
+    pub nft_outputs: usize,
+    /// The native tokens that would be received.
+    pub native_tokens: BTreeMap<TokenId, U256>,
+}
+
+/// A bech32 address split into its human readable part and the address it encodes.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedBech32AddressDto {
+    /// The human readable part.
+    pub hrp: String,
+    /// The address, containing its kind and hex encoded bytes.
+    #[serde(flatten)]
+    pub address: AddressDto,
+}
+
+/// A page of the outputs stored in the account, ordered by output id.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputsPageDto {
+    /// The outputs of the page.
+    pub outputs: Vec<OutputDataDto>,
+    /// The number of outputs matching the filter, across all pages.
+    pub total: usize,
+    /// The offset of the next page, if there is one.
+    pub next_offset: Option<usize>,
+}
+
+/// The transactions and outputs of an account, taken from the same state of the account.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryExportDto {
+    /// The time of the export, as milliseconds since the unix epoch.
+    pub timestamp: String,
+    /// The transactions sent by the account, ordered by timestamp.
+    pub transactions: Vec<TransactionDto>,
+    /// The incoming transactions of the account ordered by timestamp, if they were requested.
+    pub incoming_transactions: Option<Vec<TransactionDto>>,
+    /// The outputs of the account, ordered by output id.
+    pub outputs: Vec<OutputDataDto>,
+}
+
+/// The result of retrying all pending transactions of an account.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetriedTransactionsDto {
+    /// The ids of the blocks that included the transactions.
+    pub block_ids: Vec<BlockId>,
+    /// The transactions that couldn't be retried until they were included, with the reason.
+    pub failed: BTreeMap<TransactionId, WalletError>,
+}
+
+/// The result of syncing all accounts.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountsBalancesDto {
+    /// The balances of the synced accounts by account index.
+    pub balances: BTreeMap<u32, AccountBalanceDto>,
+    /// The accounts that couldn't be synced by account index, with the reason.
+    pub failed: BTreeMap<u32, WalletError>,
+}
+
+/// Native token metadata following the IRC-30 standard.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Irc30Metadata {
+    /// The standard, always `IRC30`.
+    pub standard: String,
+    /// The human readable name of the token.
+    pub name: String,
+    /// A description of the token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The symbol of the token.
+    pub symbol: String,
+    /// The number of decimals, the smallest unit is worth `10^-decimals` of a token.
+    pub decimals: u32,
+    /// A URL pointing to a website related to the token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// A URL pointing to the logo of the token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    /// The logo of the token as SVG or base64 encoded image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo: Option<String>,
+}
+
+impl Irc30Metadata {
+    /// The value of the `standard` field of IRC-30 metadata.
+    pub const STANDARD: &'static str = "IRC30";
+    /// The maximum number of decimals, a token of the maximum supply still has to be representable.
+    pub const MAX_DECIMALS: u32 = 77;
+}
+
+/// NFT metadata following the IRC-27 standard. Fields not defined by the standard are ignored.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Irc27Metadata {
+    /// The standard, always `IRC27`.
+    pub standard: String,
+    /// The version of the standard.
+    pub version: String,
+    /// The MIME type of the NFT file.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The URL pointing to the NFT file.
+    pub uri: String,
+    /// The human readable name of the NFT.
+    pub name: String,
+    /// The human readable name of the collection the NFT belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collection_name: Option<String>,
+    /// The royalty percentages, by bech32 address of the receiver.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub royalties: BTreeMap<String, f64>,
+    /// The human readable name of the creator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer_name: Option<String>,
+    /// A description of the NFT.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The attributes of the NFT.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<Irc27Attribute>,
+}
+
+impl Irc27Metadata {
+    /// The value of the `standard` field of IRC-27 metadata.
+    pub const STANDARD: &'static str = "IRC27";
+    /// The version of the standard used when building metadata.
+    pub const VERSION: &'static str = "v1.0";
+}
+
+/// An attribute of an NFT following the IRC-27 standard.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Irc27Attribute {
+    /// The name of the attribute.
+    pub trait_type: String,
+    /// The value of the attribute.
+    pub value: serde_json::Value,
+}
+
+/// The status of the background syncing.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackgroundSyncStatusDto {
+    /// If the background syncing is running.
+    pub running: bool,
+    /// The interval of the background syncing in milliseconds, if it's running.
+    pub interval_ms: Option<u64>,
+    /// The time of the last cycle in which all accounts got synced, as milliseconds since the unix epoch.
+    pub last_sync_timestamp: Option<u64>,
+}
+
+impl From<&BackgroundSyncStatus> for BackgroundSyncStatusDto {
+    fn from(value: &BackgroundSyncStatus) -> Self {
+        Self {
+            running: value.running,
+            interval_ms: value.interval.map(|interval| interval.as_millis() as u64),
+            last_sync_timestamp: value.last_sync_timestamp,
+        }
+    }
+}
+
+/// The parts of an output ID.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputIdPartsDto {
+    /// The ID of the transaction that created the output.
+    pub transaction_id: TransactionId,
+    /// The index of the output in the transaction.
+    pub index: u16,
+}
+
+/// The BIP44 derivation path of an address.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bip44PathDto {
+    /// The coin type.
+    pub coin_type: u32,
+    /// The account index.
+    pub account: u32,
+    /// 0 for public addresses, 1 for internal (change) addresses.
+    pub change: u32,
+    /// The address index.
+    pub address_index: u32,
+}
+
+/// Dust outputs of an account, that could be consolidated to free their storage deposit.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustReportDto {
+    /// The number of dust outputs.
+    pub dust_outputs_count: usize,
+    /// The IDs of the dust outputs.
+    pub dust_outputs: Vec<OutputId>,
+    /// The amount of base coins held by the dust outputs.
+    pub dust_amount: String,
+    /// The storage deposit that would be freed by consolidating the dust outputs into a single output.
+    pub reclaimable_storage_deposit: String,
+}
+
+/// A node of the client options, without its authentication secrets.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientNodeDto {
+    /// The node url.
+    pub url: Url,
+    /// Whether authentication is set for the node.
+    pub has_auth: bool,
+    /// Whether the node is disabled.
+    pub disabled: bool,
+}
+
+/// The client configuration currently in effect, without nodes and their authentication.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientConfigDto {
+    /// Whether node quorum is enabled.
+    pub quorum: bool,
+    /// Minimum amount of nodes required for a request when quorum is enabled.
+    pub min_quorum_size: usize,
+    /// % of nodes that have to return the same response so it gets accepted.
+    pub quorum_threshold: usize,
+    /// Whether the node health is ignored.
+    pub ignore_node_health: bool,
+    /// Interval in which the nodes are checked for their sync status.
+    pub node_sync_interval: Duration,
+    /// Timeout for API requests.
+    pub api_timeout: Duration,
+    /// Timeout when sending a block that requires remote proof of work.
+    pub remote_pow_timeout: Duration,
+    /// The bech32 HRP of the network.
+    pub bech32_hrp: String,
 }
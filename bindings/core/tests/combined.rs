@@ -4,10 +4,19 @@
 use std::collections::BTreeMap;
 
 use iota_sdk::{
-    client::{api::GetAddressesBuilderOptions, constants::SHIMMER_COIN_TYPE, secret::SecretManagerDto, ClientBuilder},
-    wallet::account::types::AccountIdentifier,
+    client::{
+        api::GetAddressesBuilderOptions,
+        constants::SHIMMER_COIN_TYPE,
+        secret::{GenerateAddressOptions, SecretManagerDto},
+        ClientBuilder,
+    },
+    types::block::output::TokenId,
+    wallet::account::{types::AccountIdentifier, SyncOptions},
+};
+use iota_sdk_bindings_core::{
+    call_wallet_method_batch, AccountMethod, CallMethod, ClientMethod, Error, Response, Result, WalletMethod,
+    WalletOptions,
 };
-use iota_sdk_bindings_core::{AccountMethod, CallMethod, ClientMethod, Response, Result, WalletMethod, WalletOptions};
 
 #[tokio::test]
 async fn generate_addresses() -> Result<()> {
@@ -93,7 +102,12 @@ async fn create_account() -> Result<()> {
     let response = wallet
         .call_method(WalletMethod::CallAccountMethod {
             account_id: AccountIdentifier::Index(0),
-            method: AccountMethod::UnspentOutputs { filter_options: None },
+            method: AccountMethod::UnspentOutputs {
+                filter_options: None,
+                offset: None,
+                limit: None,
+            },
+            timeout_ms: None,
         })
         .await;
 
@@ -174,6 +188,17 @@ async fn verify_accounts() -> Result<()> {
             response => panic!("unexpected response {response:?}"),
         }
 
+        // By Index directly
+        match wallet
+            .call_method(WalletMethod::GetAccountByIndex { index: account.index })
+            .await
+        {
+            Response::Account(details) => {
+                assert_eq!(&account_details[&details.index], &details);
+            }
+            response => panic!("unexpected response {response:?}"),
+        }
+
         // By Name
         match wallet
             .call_method(WalletMethod::GetAccount {
@@ -188,6 +213,17 @@ async fn verify_accounts() -> Result<()> {
         }
     }
 
+    // The removed account can't be found by its index anymore
+    match wallet
+        .call_method(WalletMethod::GetAccountByIndex {
+            index: account_details.len() as u32,
+        })
+        .await
+    {
+        Response::Error(_) => {}
+        response => panic!("unexpected response {response:?}"),
+    }
+
     // Get account details
     match wallet.call_method(WalletMethod::GetAccounts).await {
         Response::Accounts(details) => {
@@ -270,3 +306,741 @@ async fn client_from_wallet() -> Result<()> {
     std::fs::remove_dir_all(storage_path).ok();
     Ok(())
 }
+
+#[tokio::test]
+async fn wallet_method_batch() -> Result<()> {
+    let storage_path = "test-storage/wallet_method_batch";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let client_options = r#"{
+            "nodes":[
+               {
+                  "url":"http://localhost:14265",
+                  "auth":null,
+                  "disabled":false
+               }
+            ]
+         }"#;
+
+    let options = WalletOptions {
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet = options.build_manager().await?;
+
+    let responses = call_wallet_method_batch(
+        &wallet,
+        vec![
+            WalletMethod::CreateAccount {
+                alias: Some("Alice".to_owned()),
+                bech32_hrp: None,
+            },
+            WalletMethod::GetAccountByIndex { index: 0 },
+            // Doesn't exist, so the batch stops here
+            WalletMethod::GetAccountByIndex { index: 1 },
+            WalletMethod::CreateAccount {
+                alias: Some("Bob".to_owned()),
+                bech32_hrp: None,
+            },
+        ],
+    )
+    .await;
+
+    assert_eq!(responses.len(), 3);
+    match &responses[0] {
+        Response::Account(account) => assert_eq!(account.alias, "Alice"),
+        response => panic!("unexpected response {response:?}"),
+    }
+    match &responses[1] {
+        Response::Account(account) => assert_eq!(account.index, 0),
+        response => panic!("unexpected response {response:?}"),
+    }
+    match &responses[2] {
+        Response::Error(Error::BatchMethod { index, .. }) => assert_eq!(*index, 2),
+        response => panic!("unexpected response {response:?}"),
+    }
+
+    // The method after the failing one didn't run
+    assert_eq!(wallet.get_accounts().await?.len(), 1);
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
+
+#[tokio::test]
+async fn default_sync_options() -> Result<()> {
+    let storage_path = "test-storage/default_sync_options";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let client_options = r#"{
+            "nodes":[
+               {
+                  "url":"http://localhost:14265",
+                  "auth":null,
+                  "disabled":false
+               }
+            ]
+         }"#;
+
+    let options = WalletOptions {
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet = options.build_manager().await?;
+
+    let custom_options = SyncOptions {
+        address_start_index: 10,
+        ..Default::default()
+    };
+
+    let responses = call_wallet_method_batch(
+        &wallet,
+        vec![
+            WalletMethod::CreateAccount {
+                alias: None,
+                bech32_hrp: None,
+            },
+            WalletMethod::CallAccountMethod {
+                account_id: AccountIdentifier::Index(0),
+                method: AccountMethod::GetDefaultSyncOptions,
+                timeout_ms: None,
+            },
+            WalletMethod::SetDefaultSyncOptionsForAll {
+                options: custom_options.clone(),
+            },
+            WalletMethod::CallAccountMethod {
+                account_id: AccountIdentifier::Index(0),
+                method: AccountMethod::GetDefaultSyncOptions,
+                timeout_ms: None,
+            },
+        ],
+    )
+    .await;
+
+    match &responses[1] {
+        Response::SyncOptions(options) => assert_eq!(options, &SyncOptions::default()),
+        response => panic!("unexpected response {response:?}"),
+    }
+    match &responses[3] {
+        Response::SyncOptions(options) => assert_eq!(options, &custom_options),
+        response => panic!("unexpected response {response:?}"),
+    }
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
+
+#[tokio::test]
+async fn addresses_with_paths() -> Result<()> {
+    let storage_path = "test-storage/addresses_with_paths";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let client_options = r#"{
+            "nodes":[
+               {
+                  "url":"http://localhost:14265",
+                  "auth":null,
+                  "disabled":false
+               }
+            ]
+         }"#;
+
+    let options = WalletOptions {
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet = options.build_manager().await?;
+
+    let responses = call_wallet_method_batch(
+        &wallet,
+        vec![
+            WalletMethod::CreateAccount {
+                alias: None,
+                bech32_hrp: None,
+            },
+            WalletMethod::CreateAccount {
+                alias: None,
+                bech32_hrp: None,
+            },
+            WalletMethod::CallAccountMethod {
+                account_id: AccountIdentifier::Index(1),
+                method: AccountMethod::GenerateAddresses {
+                    amount: 1,
+                    options: Some(GenerateAddressOptions::internal()),
+                },
+                timeout_ms: None,
+            },
+            WalletMethod::CallAccountMethod {
+                account_id: AccountIdentifier::Index(1),
+                method: AccountMethod::GetAddressesWithPaths,
+                timeout_ms: None,
+            },
+        ],
+    )
+    .await;
+
+    match &responses[3] {
+        Response::AddressesWithPaths(addresses) => {
+            assert_eq!(addresses.len(), 2);
+            for (_, path) in addresses {
+                assert_eq!(path.coin_type, SHIMMER_COIN_TYPE);
+                assert_eq!(path.account, 1);
+                assert_eq!(path.address_index, 0);
+            }
+            assert_eq!(addresses[0].1.change, 0);
+            assert_eq!(addresses[1].1.change, 1);
+
+            // Public and internal addresses lead to the account that generated them
+            for (address, _) in addresses {
+                let response = wallet
+                    .call_method(WalletMethod::FindAccountForAddress {
+                        address: address.clone(),
+                    })
+                    .await;
+                match response {
+                    Response::Account(account) => assert_eq!(account.index, 1),
+                    response => panic!("unexpected response {response:?}"),
+                }
+
+                for index in [0, 1] {
+                    let response = wallet
+                        .call_method(WalletMethod::CallAccountMethod {
+                            account_id: AccountIdentifier::Index(index),
+                            method: AccountMethod::IsAddressMine {
+                                address: address.clone(),
+                                address_gap_limit: None,
+                            },
+                            timeout_ms: None,
+                        })
+                        .await;
+                    match response {
+                        Response::Bool(is_mine) => assert_eq!(is_mine, index == 1),
+                        response => panic!("unexpected response {response:?}"),
+                    }
+                }
+            }
+        }
+        response => panic!("unexpected response {response:?}"),
+    }
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
+
+#[tokio::test]
+async fn outputs_page() -> Result<()> {
+    let storage_path = "test-storage/outputs_page";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let client_options = r#"{
+            "nodes":[
+               {
+                  "url":"http://localhost:14265",
+                  "auth":null,
+                  "disabled":false
+               }
+            ]
+         }"#;
+
+    let options = WalletOptions {
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet = options.build_manager().await?;
+
+    let responses = call_wallet_method_batch(
+        &wallet,
+        vec![
+            WalletMethod::CreateAccount {
+                alias: None,
+                bech32_hrp: None,
+            },
+            WalletMethod::CallAccountMethod {
+                account_id: AccountIdentifier::Index(0),
+                method: AccountMethod::Outputs {
+                    filter_options: None,
+                    offset: Some(0),
+                    limit: Some(10),
+                },
+                timeout_ms: None,
+            },
+        ],
+    )
+    .await;
+
+    match &responses[1] {
+        Response::OutputsPage(page) => {
+            assert!(page.outputs.is_empty());
+            assert_eq!(page.total, 0);
+            assert_eq!(page.next_offset, None);
+        }
+        response => panic!("unexpected response {response:?}"),
+    }
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_spent_outputs() -> Result<()> {
+    let storage_path = "test-storage/get_spent_outputs";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let client_options = r#"{
+            "nodes":[
+               {
+                  "url":"http://localhost:14265",
+                  "auth":null,
+                  "disabled":false
+               }
+            ]
+         }"#;
+
+    let options = WalletOptions {
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet = options.build_manager().await?;
+
+    let responses = call_wallet_method_batch(
+        &wallet,
+        vec![
+            WalletMethod::CreateAccount {
+                alias: None,
+                bech32_hrp: None,
+            },
+            WalletMethod::CallAccountMethod {
+                account_id: AccountIdentifier::Index(0),
+                method: AccountMethod::GetSpentOutputs {
+                    offset: None,
+                    limit: None,
+                },
+                timeout_ms: None,
+            },
+        ],
+    )
+    .await;
+
+    match &responses[1] {
+        Response::OutputsPage(page) => {
+            assert!(page.outputs.is_empty());
+            assert_eq!(page.total, 0);
+            assert_eq!(page.next_offset, None);
+        }
+        response => panic!("unexpected response {response:?}"),
+    }
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_sync_status() -> Result<()> {
+    let storage_path = "test-storage/get_sync_status";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let client_options = r#"{
+            "nodes":[
+               {
+                  "url":"http://localhost:14265",
+                  "auth":null,
+                  "disabled":false
+               }
+            ]
+         }"#;
+
+    let options = WalletOptions {
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet = options.build_manager().await?;
+
+    let responses = call_wallet_method_batch(
+        &wallet,
+        vec![
+            WalletMethod::CreateAccount {
+                alias: None,
+                bech32_hrp: None,
+            },
+            WalletMethod::CallAccountMethod {
+                account_id: AccountIdentifier::Index(0),
+                method: AccountMethod::GetSyncStatus,
+                timeout_ms: None,
+            },
+        ],
+    )
+    .await;
+
+    match &responses[1] {
+        Response::SyncStatus(status) => {
+            // The account wasn't synced yet
+            assert_eq!(status.last_synced_at, None);
+            assert_eq!(status.synced_up_to_milestone, None);
+        }
+        response => panic!("unexpected response {response:?}"),
+    }
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
+
+#[tokio::test]
+async fn native_tokens_metadata_of_unknown_token() -> Result<()> {
+    let storage_path = "test-storage/native_tokens_metadata_of_unknown_token";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let client_options = r#"{
+            "nodes":[
+               {
+                  "url":"http://localhost:14265",
+                  "auth":null,
+                  "disabled":false
+               }
+            ]
+         }"#;
+
+    let options = WalletOptions {
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet = options.build_manager().await?;
+
+    let responses = call_wallet_method_batch(
+        &wallet,
+        vec![
+            WalletMethod::CreateAccount {
+                alias: None,
+                bech32_hrp: None,
+            },
+            WalletMethod::CallAccountMethod {
+                account_id: AccountIdentifier::Index(0),
+                method: AccountMethod::GetNativeTokensMetadata {
+                    token_ids: vec![TokenId::null()],
+                },
+                timeout_ms: None,
+            },
+        ],
+    )
+    .await;
+
+    // Unknown tokens have no metadata, instead of failing the whole call
+    match &responses[1] {
+        Response::NativeTokensMetadata(tokens_metadata) => {
+            assert_eq!(tokens_metadata.len(), 1);
+            assert_eq!(tokens_metadata[0].0, TokenId::null());
+            assert!(tokens_metadata[0].1.is_none());
+        }
+        response => panic!("unexpected response {response:?}"),
+    }
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
+
+#[tokio::test]
+async fn account_method_timeout() -> Result<()> {
+    let storage_path = "test-storage/account_method_timeout";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let client_options = r#"{
+            "nodes":[
+               {
+                  "url":"http://localhost:14265",
+                  "auth":null,
+                  "disabled":false
+               }
+            ]
+         }"#;
+
+    let options = WalletOptions {
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet = options.build_manager().await?;
+
+    let responses = call_wallet_method_batch(
+        &wallet,
+        vec![
+            WalletMethod::CreateAccount {
+                alias: None,
+                bech32_hrp: None,
+            },
+            WalletMethod::CallAccountMethod {
+                account_id: AccountIdentifier::Index(0),
+                method: AccountMethod::GetSyncStatus,
+                timeout_ms: Some(10_000),
+            },
+        ],
+    )
+    .await;
+
+    match &responses[1] {
+        Response::SyncStatus(_) => {}
+        response => panic!("unexpected response {response:?}"),
+    }
+
+    // Methods that lock or submit outputs can't be cancelled by a timeout
+    let response = wallet
+        .call_method(WalletMethod::CallAccountMethod {
+            account_id: AccountIdentifier::Index(0),
+            method: AccountMethod::SetAlias {
+                alias: "new_alias".to_string(),
+            },
+            timeout_ms: Some(10_000),
+        })
+        .await;
+
+    match response {
+        Response::Error(Error::TimeoutNotSupported) => {}
+        _ => panic!("unexpected response {response:?}"),
+    }
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
+
+#[tokio::test]
+async fn export_history() -> Result<()> {
+    let storage_path = "test-storage/export_history";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let client_options = r#"{
+            "nodes":[
+               {
+                  "url":"http://localhost:14265",
+                  "auth":null,
+                  "disabled":false
+               }
+            ]
+         }"#;
+
+    let options = WalletOptions {
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet = options.build_manager().await?;
+
+    let responses = call_wallet_method_batch(
+        &wallet,
+        vec![
+            WalletMethod::CreateAccount {
+                alias: None,
+                bech32_hrp: None,
+            },
+            WalletMethod::CallAccountMethod {
+                account_id: AccountIdentifier::Index(0),
+                method: AccountMethod::ExportHistory {
+                    include_incoming: false,
+                },
+                timeout_ms: None,
+            },
+            WalletMethod::CallAccountMethod {
+                account_id: AccountIdentifier::Index(0),
+                method: AccountMethod::ExportHistory { include_incoming: true },
+                timeout_ms: None,
+            },
+        ],
+    )
+    .await;
+
+    for (response, include_incoming) in responses[1..].iter().zip([false, true]) {
+        match response {
+            Response::HistoryExport(export) => {
+                assert!(export.transactions.is_empty());
+                assert_eq!(export.incoming_transactions.is_some(), include_incoming);
+                assert!(export.outputs.is_empty());
+            }
+            response => panic!("unexpected response {response:?}"),
+        }
+    }
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_client_nodes() -> Result<()> {
+    let storage_path = "test-storage/get_client_nodes";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let client_options = r#"{
+            "nodes":[
+               {
+                  "url":"http://localhost:14265",
+                  "auth":null,
+                  "disabled":false
+               }
+            ]
+         }"#;
+
+    let options = WalletOptions {
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet = options.build_manager().await?;
+
+    let response = wallet.call_method(WalletMethod::GetClientNodes).await;
+
+    match response {
+        Response::Nodes(nodes) => {
+            assert_eq!(nodes.len(), 1);
+            assert_eq!(nodes[0].url.as_str(), "http://localhost:14265/");
+            assert!(!nodes[0].has_auth);
+            assert!(!nodes[0].disabled);
+        }
+        _ => panic!("unexpected response {response:?}"),
+    }
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_client_config() -> Result<()> {
+    let storage_path = "test-storage/get_client_config";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let client_options = r#"{
+            "nodes":[
+               {
+                  "url":"http://localhost:14265",
+                  "auth":null,
+                  "disabled":false
+               }
+            ]
+         }"#;
+
+    let options = WalletOptions {
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet = options.build_manager().await?;
+
+    let response = wallet.call_method(WalletMethod::GetClientConfig).await;
+
+    match response {
+        Response::ClientConfig(config) => {
+            assert!(!config.quorum);
+            assert!(!config.ignore_node_health);
+        }
+        _ => panic!("unexpected response {response:?}"),
+    }
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
+
+#[cfg(feature = "events")]
+#[tokio::test]
+async fn emit_test_event_of_type() -> Result<()> {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use iota_sdk::wallet::events::types::WalletEventType;
+
+    let storage_path = "test-storage/emit_test_event_of_type";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let client_options = r#"{
+            "nodes":[
+               {
+                  "url":"http://localhost:14265",
+                  "auth":null,
+                  "disabled":false
+               }
+            ]
+         }"#;
+
+    let options = WalletOptions {
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet = options.build_manager().await?;
+
+    let event_counter = Arc::new(AtomicUsize::new(0));
+    let event_counter_clone = Arc::clone(&event_counter);
+    wallet
+        .listen(vec![], move |_event| {
+            event_counter_clone.fetch_add(1, Ordering::SeqCst);
+        })
+        .await;
+
+    match wallet.call_method(WalletMethod::GetActiveListeners).await {
+        Response::ListenerTypes(listener_types) => {
+            assert!(listener_types.contains(&(WalletEventType::NewOutput, 1)))
+        }
+        response => panic!("unexpected response {response:?}"),
+    }
+
+    let event_types = [
+        WalletEventType::ConsolidationRequired,
+        WalletEventType::NewOutput,
+        WalletEventType::SpentOutput,
+        WalletEventType::SyncProgress,
+        WalletEventType::TransactionInclusion,
+        WalletEventType::TransactionProgress,
+    ];
+    for event_type in event_types {
+        match wallet
+            .call_method(WalletMethod::EmitTestEventOfType { event_type })
+            .await
+        {
+            Response::Ok => {}
+            response => panic!("unexpected response {response:?}"),
+        }
+    }
+    assert_eq!(event_counter.load(Ordering::SeqCst), event_types.len());
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
@@ -43,6 +43,9 @@ fn method_interface_secrets_debug() {
     let response = Response::GeneratedMnemonic("mnemonic".to_string());
     assert_eq!(format!("{:?}", response), "GeneratedMnemonic(<omitted>)");
 
+    let response = Response::MnemonicHexSeed("0x".to_string());
+    assert_eq!(format!("{:?}", response), "MnemonicHexSeed(<omitted>)");
+
     let wallet_options = WalletOptions {
         storage_path: None,
         client_options: None,
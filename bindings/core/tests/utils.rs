@@ -1,7 +1,31 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk_bindings_core::{call_utils_method, Response, Result, UtilsMethod};
+use std::str::FromStr;
+
+use iota_sdk::{
+    client::{
+        api::GetAddressesBuilderOptions,
+        constants::{HD_WALLET_TYPE, SHIMMER_COIN_TYPE},
+        secret::{GenerateAddressOptions, SecretManager},
+    },
+    types::block::{
+        address::{
+            dto::{AddressDto, Ed25519AddressDto},
+            Address, AliasAddress,
+        },
+        output::{
+            dto::{OutputDto, RentStructureDto},
+            unlock_condition::{AddressUnlockCondition, ImmutableAliasAddressUnlockCondition},
+            AliasId, BasicOutputBuilder, FoundryId, FoundryOutputBuilder, NftId, OutputId, Rent, SimpleTokenScheme,
+            TokenScheme, OUTPUT_INDEX_MAX,
+        },
+        payload::transaction::TransactionId,
+        protocol::{dto::ProtocolParametersDto, protocol_parameters},
+    },
+    U256,
+};
+use iota_sdk_bindings_core::{call_utils_method, CallMethod, Response, Result, SecretManagerMethod, UtilsMethod};
 
 #[tokio::test]
 async fn utils() -> Result<()> {
@@ -34,3 +58,537 @@ async fn utils() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn verify_mnemonic() -> Result<()> {
+    let mnemonic = "until fire hat mountain zoo grocery real deny advance change marble taste goat ivory wheat bubble panic banner tattoo client ticket action race rocket".to_string();
+    let response = call_utils_method(UtilsMethod::VerifyMnemonic {
+        mnemonic: mnemonic.clone(),
+    });
+    match response {
+        Response::Ok => {}
+        _ => panic!("Unexpected response type"),
+    };
+
+    let invalid_mnemonics = [
+        // wrong checksum
+        "fire until hat mountain zoo grocery real deny advance change marble taste goat ivory wheat bubble panic banner tattoo client ticket action race rocket".to_string(),
+        // wrong word count
+        "invalid mnemonic".to_string(),
+        // surrounding whitespace
+        format!(" {mnemonic} "),
+        // mixed casing
+        mnemonic.replacen("until", "Until", 1),
+    ];
+    for mnemonic in invalid_mnemonics {
+        match call_utils_method(UtilsMethod::VerifyMnemonic { mnemonic }) {
+            Response::Error(_) => {}
+            _ => panic!("Unexpected response type"),
+        };
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mnemonic_to_hex_seed() -> Result<()> {
+    let mnemonic = "until fire hat mountain zoo grocery real deny advance change marble taste goat ivory wheat bubble panic banner tattoo client ticket action race rocket".to_string();
+
+    let mut seeds = Vec::new();
+    for _ in 0..2 {
+        match call_utils_method(UtilsMethod::MnemonicToHexSeed {
+            mnemonic: mnemonic.clone(),
+        }) {
+            Response::MnemonicHexSeed(seed) => {
+                // 0x prefix + 64 bytes
+                assert_eq!(seed.len(), 2 + 2 * 64);
+                seeds.push(seed);
+            }
+            _ => panic!("Unexpected response type"),
+        };
+    }
+    assert_eq!(seeds[0], seeds[1]);
+
+    match call_utils_method(UtilsMethod::MnemonicToHexSeed {
+        mnemonic: "invalid mnemonic".to_string(),
+    }) {
+        Response::Error(_) => {}
+        _ => panic!("Unexpected response type"),
+    };
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn derive_address() -> Result<()> {
+    let mnemonic = "endorse answer radar about source reunion marriage tag sausage weekend frost daring base attack because joke dream slender leisure group reason prepare broken river".to_string();
+    let seed_hex = match call_utils_method(UtilsMethod::MnemonicToHexSeed { mnemonic }) {
+        Response::MnemonicHexSeed(seed_hex) => seed_hex,
+        _ => panic!("Unexpected response type"),
+    };
+
+    let derived_address = match call_utils_method(UtilsMethod::DeriveAddress {
+        seed_hex: seed_hex.clone(),
+        coin_type: SHIMMER_COIN_TYPE,
+        account_index: 0,
+        internal: false,
+        address_index: 0,
+        bech32_hrp: "rms".to_string(),
+    }) {
+        Response::Bech32Address(address) => address,
+        _ => panic!("Unexpected response type"),
+    };
+    assert_eq!(
+        derived_address,
+        "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy"
+    );
+
+    // cross-check with the secret manager used by the wallet
+    let secret_manager = SecretManager::try_from_hex_seed(&seed_hex)?;
+    let options = GetAddressesBuilderOptions {
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        account_index: Some(1),
+        range: Some(5..6),
+        bech32_hrp: Some("rms".to_string()),
+        options: Some(GenerateAddressOptions::internal()),
+    };
+    let generated_address = match secret_manager
+        .call_method(SecretManagerMethod::GenerateAddresses { options })
+        .await
+    {
+        Response::GeneratedAddresses(addresses) => addresses[0].clone(),
+        _ => panic!("Unexpected response type"),
+    };
+
+    match call_utils_method(UtilsMethod::DeriveAddress {
+        seed_hex,
+        coin_type: SHIMMER_COIN_TYPE,
+        account_index: 1,
+        internal: true,
+        address_index: 5,
+        bech32_hrp: "rms".to_string(),
+    }) {
+        Response::Bech32Address(address) => assert_eq!(address, generated_address),
+        _ => panic!("Unexpected response type"),
+    };
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn compute_output_id() -> Result<()> {
+    let transaction_id = TransactionId::from_str("0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649")?;
+
+    match call_utils_method(UtilsMethod::ComputeOutputId {
+        transaction_id,
+        index: 42,
+    }) {
+        Response::OutputId(output_id) => assert_eq!(
+            output_id.to_string(),
+            "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6492a00"
+        ),
+        _ => panic!("Unexpected response type"),
+    };
+
+    // the index has to be lower than the maximum amount of outputs in a transaction
+    match call_utils_method(UtilsMethod::ComputeOutputId {
+        transaction_id,
+        index: OUTPUT_INDEX_MAX + 1,
+    }) {
+        Response::Error(_) => {}
+        _ => panic!("Unexpected response type"),
+    };
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn output_id_to_transaction_id_and_index() -> Result<()> {
+    let output_id = OutputId::from_str("0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6492a00")?;
+
+    match call_utils_method(UtilsMethod::OutputIdToTransactionIdAndIndex { output_id }) {
+        Response::OutputIdParts(parts) => {
+            assert_eq!(
+                parts.transaction_id.to_string(),
+                "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649"
+            );
+            assert_eq!(parts.index, 42);
+        }
+        _ => panic!("Unexpected response type"),
+    };
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn compute_ids() -> Result<()> {
+    let output_id = OutputId::from_str("0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6492a00")?;
+
+    // a newly created alias or nft output has a null id, the actual id is derived from the output id
+    let alias_id = match call_utils_method(UtilsMethod::ComputeAliasId { output_id }) {
+        Response::AliasId(alias_id) => alias_id,
+        _ => panic!("Unexpected response type"),
+    };
+    assert!(!alias_id.is_null());
+    assert_eq!(alias_id, AliasId::null().or_from_output_id(&output_id));
+
+    match call_utils_method(UtilsMethod::ComputeNftId { output_id }) {
+        Response::NftId(nft_id) => {
+            assert!(!nft_id.is_null());
+            assert_eq!(nft_id, NftId::null().or_from_output_id(&output_id));
+        }
+        _ => panic!("Unexpected response type"),
+    };
+
+    let alias_address = AliasAddress::new(alias_id);
+    match call_utils_method(UtilsMethod::ComputeFoundryId {
+        alias_address,
+        serial_number: 1,
+        token_scheme_kind: SimpleTokenScheme::KIND,
+    }) {
+        Response::FoundryId(foundry_id) => {
+            assert_eq!(foundry_id, FoundryId::build(&alias_address, 1, SimpleTokenScheme::KIND))
+        }
+        _ => panic!("Unexpected response type"),
+    };
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn compute_token_id() -> Result<()> {
+    let alias_id = AliasId::from_str("0xcf077d276686ba64c0404b9eb2d15556782113c5a1985f262b70f9964d3bbd7f")?;
+    let foundry = FoundryOutputBuilder::new_with_amount(
+        1_000_000,
+        1,
+        TokenScheme::Simple(SimpleTokenScheme::new(U256::from(100), U256::from(0), U256::from(100))?),
+    )
+    .add_unlock_condition(ImmutableAliasAddressUnlockCondition::new(AliasAddress::new(alias_id)))
+    .finish(protocol_parameters().token_supply())?;
+
+    match call_utils_method(UtilsMethod::ComputeTokenId {
+        alias_id,
+        serial_number: 1,
+        token_scheme_kind: SimpleTokenScheme::KIND,
+    }) {
+        Response::TokenId(token_id) => assert_eq!(token_id, foundry.token_id()),
+        _ => panic!("Unexpected response type"),
+    };
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn parse_bech32_address() -> Result<()> {
+    let bech32_address = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy".to_string();
+
+    match call_utils_method(UtilsMethod::ParseBech32Address {
+        address: bech32_address.clone(),
+    }) {
+        Response::ParsedBech32Address(parsed) => {
+            assert_eq!(parsed.hrp, "rms");
+            assert_eq!(
+                Address::try_from(&parsed.address)?,
+                Address::try_from_bech32(&bech32_address)?
+            );
+            assert!(matches!(parsed.address, AddressDto::Ed25519(_)));
+        }
+        _ => panic!("Unexpected response type"),
+    };
+
+    let invalid_addresses = [
+        // malformed
+        "rms1".to_string(),
+        // wrong checksum
+        bech32_address.replace("70zy", "70zz"),
+        // unknown address kind
+        "rms1q9llaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxc5tspn".to_string(),
+    ];
+    for address in invalid_addresses {
+        match call_utils_method(UtilsMethod::ParseBech32Address { address }) {
+            Response::Error(_) => {}
+            _ => panic!("Unexpected response type"),
+        };
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn change_bech32_hrp() -> Result<()> {
+    let bech32_address = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy".to_string();
+
+    match call_utils_method(UtilsMethod::ChangeBech32Hrp {
+        address: bech32_address.clone(),
+        new_hrp: "smr".to_string(),
+    }) {
+        Response::Bech32Address(address) => {
+            let (hrp, inner) = Address::try_from_bech32_with_hrp(&address)?;
+            assert_eq!(hrp, "smr");
+            assert_eq!(inner, Address::try_from_bech32(&bech32_address)?);
+        }
+        _ => panic!("Unexpected response type"),
+    };
+
+    match call_utils_method(UtilsMethod::ChangeBech32Hrp {
+        address: bech32_address.replace("70zy", "70zz"),
+        new_hrp: "smr".to_string(),
+    }) {
+        Response::Error(_) => {}
+        _ => panic!("Unexpected response type"),
+    };
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn verify_ed25519_signature() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(
+        "endorse answer radar about source reunion marriage tag sausage weekend frost daring base attack because joke dream slender leisure group reason prepare broken river",
+    )?;
+    let message = prefix_hex::encode("challenge".as_bytes());
+
+    let signature = match secret_manager
+        .call_method(SecretManagerMethod::SignEd25519 {
+            message: message.clone(),
+            chain: vec![HD_WALLET_TYPE, SHIMMER_COIN_TYPE, 0, 0, 0],
+        })
+        .await
+    {
+        Response::Ed25519Signature(signature) => signature,
+        _ => panic!("Unexpected response type"),
+    };
+    let address = Ed25519AddressDto::from(
+        Address::try_from_bech32("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")?.as_ed25519(),
+    );
+
+    // valid signature, with and without checking the address
+    for address in [None, Some(address.clone())] {
+        match call_utils_method(UtilsMethod::VerifyEd25519Signature {
+            signature: signature.clone(),
+            message: message.clone(),
+            address,
+        }) {
+            Response::Bool(valid) => assert!(valid),
+            _ => panic!("Unexpected response type"),
+        };
+    }
+
+    // signature for a different message
+    match call_utils_method(UtilsMethod::VerifyEd25519Signature {
+        signature: signature.clone(),
+        message: prefix_hex::encode("other challenge".as_bytes()),
+        address: Some(address),
+    }) {
+        Response::Bool(valid) => assert!(!valid),
+        _ => panic!("Unexpected response type"),
+    };
+
+    // malformed public key
+    let mut malformed_signature = signature;
+    malformed_signature.public_key = "0x1234".to_string();
+    match call_utils_method(UtilsMethod::VerifyEd25519Signature {
+        signature: malformed_signature,
+        message,
+        address: None,
+    }) {
+        Response::Error(_) => {}
+        _ => panic!("Unexpected response type"),
+    };
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn blake2b256() -> Result<()> {
+    let hashes = [
+        (
+            "0x",
+            "0x0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8",
+        ),
+        (
+            "0x00",
+            "0x03170a2e7597b7b7e3d84c05391d139a62b157e78786d8c082f29dcf4c111314",
+        ),
+    ];
+    for (data, hash) in hashes {
+        match call_utils_method(UtilsMethod::Blake2b256 { data: data.to_string() }) {
+            Response::Hash(result) => assert_eq!(result, hash),
+            _ => panic!("Unexpected response type"),
+        };
+    }
+
+    match call_utils_method(UtilsMethod::Blake2b256 {
+        data: "not hex".to_string(),
+    }) {
+        Response::Error(_) => {}
+        _ => panic!("Unexpected response type"),
+    };
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn compute_storage_deposit() -> Result<()> {
+    let params = protocol_parameters();
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(Address::try_from_bech32(
+            "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy",
+        )?))
+        .finish_output(params.token_supply())?;
+    let rent_structure = params.rent_structure();
+
+    match call_utils_method(UtilsMethod::ComputeStorageDeposit {
+        output: OutputDto::from(&output),
+        rent_structure: RentStructureDto {
+            v_byte_cost: rent_structure.byte_cost(),
+            v_byte_factor_key: rent_structure.byte_factor_key(),
+            v_byte_factor_data: rent_structure.byte_factor_data(),
+        },
+    }) {
+        Response::MinimumRequiredStorageDeposit(deposit) => {
+            assert_eq!(deposit, output.rent_cost(rent_structure).to_string())
+        }
+        response => panic!("Unexpected response type: {response:?}"),
+    };
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn validate_output() -> Result<()> {
+    let params = protocol_parameters();
+    let rent_structure = params.rent_structure();
+    let protocol_parameters = ProtocolParametersDto {
+        protocol_version: params.protocol_version(),
+        network_name: params.network_name().to_string(),
+        bech32_hrp: params.bech32_hrp().to_string(),
+        min_pow_score: params.min_pow_score(),
+        below_max_depth: params.below_max_depth(),
+        rent_structure: RentStructureDto {
+            v_byte_cost: rent_structure.byte_cost(),
+            v_byte_factor_key: rent_structure.byte_factor_key(),
+            v_byte_factor_data: rent_structure.byte_factor_data(),
+        },
+        token_supply: params.token_supply().to_string(),
+    };
+    let output_builder =
+        BasicOutputBuilder::new_with_amount(1_000_000).add_unlock_condition(AddressUnlockCondition::new(
+            Address::try_from_bech32("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")?,
+        ));
+
+    match call_utils_method(UtilsMethod::ValidateOutput {
+        output: OutputDto::from(&output_builder.clone().finish_output(params.token_supply())?),
+        protocol_parameters: protocol_parameters.clone(),
+    }) {
+        Response::Ok => {}
+        response => panic!("Unexpected response type: {response:?}"),
+    };
+
+    // The amount doesn't cover the storage deposit
+    match call_utils_method(UtilsMethod::ValidateOutput {
+        output: OutputDto::from(&output_builder.with_amount(1).finish_output(params.token_supply())?),
+        protocol_parameters,
+    }) {
+        Response::Error(_) => {}
+        response => panic!("Unexpected response type: {response:?}"),
+    };
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn parse_irc_27_metadata() -> Result<()> {
+    let metadata = r#"{"standard":"IRC27","version":"v1.0","type":"image/png","uri":"https://mywebsite.com/my-nft-files-1.png","name":"My NFT #0001","collectionName":"My Collection of Art","attributes":[{"trait_type":"Background","value":"Purple"}],"unknownField":true}"#;
+
+    match call_utils_method(UtilsMethod::ParseIrc27Metadata {
+        metadata: prefix_hex::encode(metadata.as_bytes()),
+    }) {
+        Response::Irc27Metadata(metadata) => {
+            assert_eq!(metadata.kind, "image/png");
+            assert_eq!(metadata.name, "My NFT #0001");
+            assert_eq!(metadata.collection_name.as_deref(), Some("My Collection of Art"));
+            assert_eq!(metadata.attributes[0].trait_type, "Background");
+        }
+        response => panic!("Unexpected response type: {response:?}"),
+    };
+
+    let metadata =
+        r#"{"standard":"IRC30","version":"v1.0","type":"image/png","uri":"https://mywebsite.com","name":"NFT"}"#;
+    match call_utils_method(UtilsMethod::ParseIrc27Metadata {
+        metadata: prefix_hex::encode(metadata.as_bytes()),
+    }) {
+        Response::Error(_) => {}
+        response => panic!("Unexpected response type: {response:?}"),
+    };
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_irc_30_metadata() -> Result<()> {
+    match call_utils_method(UtilsMethod::BuildIrc30Metadata {
+        name: "NativeToken".to_string(),
+        symbol: "NT".to_string(),
+        decimals: 6,
+        description: Some("A native token".to_string()),
+        url: None,
+        logo_url: Some("https://my.website/nativeToken.png".to_string()),
+    }) {
+        Response::MetadataHex(metadata) => assert_eq!(
+            prefix_hex::decode::<Vec<u8>>(metadata)?,
+            r#"{"standard":"IRC30","name":"NativeToken","description":"A native token","symbol":"NT","decimals":6,"logoUrl":"https://my.website/nativeToken.png"}"#.as_bytes()
+        ),
+        response => panic!("Unexpected response type: {response:?}"),
+    };
+
+    match call_utils_method(UtilsMethod::BuildIrc30Metadata {
+        name: "NativeToken".to_string(),
+        symbol: String::new(),
+        decimals: 6,
+        description: None,
+        url: None,
+        logo_url: None,
+    }) {
+        Response::Error(_) => {}
+        response => panic!("Unexpected response type: {response:?}"),
+    };
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn build_irc_27_metadata() -> Result<()> {
+    match call_utils_method(UtilsMethod::BuildIrc27Metadata {
+        nft_type: "image/png".to_string(),
+        uri: "https://mywebsite.com/my-nft-files-1.png".to_string(),
+        name: "My NFT #0001".to_string(),
+        collection_name: None,
+        royalties: None,
+        issuer_name: None,
+        description: None,
+        attributes: None,
+    }) {
+        Response::MetadataHex(metadata) => assert_eq!(
+            prefix_hex::decode::<Vec<u8>>(metadata)?,
+            r#"{"standard":"IRC27","version":"v1.0","type":"image/png","uri":"https://mywebsite.com/my-nft-files-1.png","name":"My NFT #0001"}"#.as_bytes()
+        ),
+        response => panic!("Unexpected response type: {response:?}"),
+    };
+
+    match call_utils_method(UtilsMethod::BuildIrc27Metadata {
+        nft_type: "png".to_string(),
+        uri: "https://mywebsite.com/my-nft-files-1.png".to_string(),
+        name: "My NFT #0001".to_string(),
+        collection_name: None,
+        royalties: None,
+        issuer_name: None,
+        description: None,
+        attributes: None,
+    }) {
+        Response::Error(_) => {}
+        response => panic!("Unexpected response type: {response:?}"),
+    };
+
+    Ok(())
+}
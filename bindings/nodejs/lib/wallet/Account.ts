@@ -280,13 +280,13 @@ export class Account {
      * @param meltAmount To be melted amount.
      * @param transactionOptions The options to define a `RemainderValueStrategy`
      * or custom inputs.
-     * @returns The transaction.
+     * @returns The melting transaction and the token ID.
      */
     async decreaseNativeTokenSupply(
         tokenId: string,
         meltAmount: HexEncodedAmount,
         transactionOptions?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<MintTokenTransaction> {
         const resp = await this.methodHandler.callAccountMethod(
             this.meta.index,
             {
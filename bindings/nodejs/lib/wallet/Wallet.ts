@@ -196,8 +196,9 @@ export class Wallet {
 
     /**
      * Clear the callbacks for provided events. An empty array will clear all listeners.
+     * @returns The number of removed listeners.
      */
-    async clearListeners(eventTypes: EventType[]): Promise<void> {
+    async clearListeners(eventTypes: EventType[]): Promise<number> {
         const response = await this.methodHandler.callMethod({
             name: 'clearListeners',
             data: { eventTypes },
@@ -32,6 +32,8 @@ export interface IClientOptions {
     remotePowTimeout?: IDuration;
     /** The amount of threads to be used for proof of work */
     powWorkerCount?: number;
+    /** How long the network info is cached before it's requested from the node again, only used in Wasm */
+    networkInfoCacheTtl?: IDuration;
     /** Whether the PoW should be done locally or remotely. */
     localPow?: boolean;
 }
@@ -8,6 +8,7 @@ export type EventType =
     | 'LedgerAddressGeneration'
     | 'NewOutput'
     | 'SpentOutput'
+    | 'SyncProgress'
     | 'TransactionInclusion'
     | 'TransactionProgress';
 
@@ -23,6 +24,7 @@ export enum WalletEvent {
     LedgerAddressGeneration = 'LedgerAddressGeneration',
     NewOutput = 'NewOutput',
     SpentOutput = 'SpentOutput',
+    SyncProgress = 'SyncProgress',
     TransactionInclusion = 'TransactionInclusion',
     TransactionProgress = 'TransactionProgress',
 }
@@ -23,5 +23,5 @@ token_id = "0x08429fe5864378ce70699fc2d22bb144cb86a3c4833d136e3b95c5dadfd6ba0cef
 melt_amount = "0x20"
 
 # Send transaction.
-transaction = account.decrease_native_token_supply(token_id, melt_amount)
+transaction = account.decrease_native_token_supply(token_id, melt_amount)['transaction']
 print(f'Block sent: {os.environ["EXPLORER_URL"]}/block/{transaction["blockId"]}')
@@ -246,6 +246,7 @@ class Wallet():
     def clear_listeners(self, events: Optional[List[str]] = None):
         """Remove wallet event listeners, empty array or None will remove all listeners
            The default value for events is None
+           Returns the number of removed listeners
         """
         events_array = [] if events is None else events
         return self._call_method(
@@ -7,6 +7,8 @@ use std::{collections::HashMap, sync::Arc, time::Duration};
 use serde::{Deserialize, Serialize};
 
 use super::{node_manager::builder::NodeManagerBuilder, ClientInner};
+#[cfg(target_family = "wasm")]
+use crate::client::constants::DEFAULT_NETWORK_INFO_CACHE_TTL;
 #[cfg(feature = "mqtt")]
 use crate::client::node_api::mqtt::{BrokerOptions, MqttEvent};
 use crate::{
@@ -128,12 +130,23 @@ pub struct ClientBuilder {
     #[serde(default)]
     #[cfg(not(target_family = "wasm"))]
     pub pow_worker_count: Option<usize>,
+    /// How long the protocol parameters and other network info are cached before they're requested from the node
+    /// again. Only available in WASM, which has no node syncing process. Outside of WASM, the network info is updated
+    /// with every node sync instead, so how up to date it is depends on the node sync interval.
+    #[serde(default = "default_network_info_cache_ttl")]
+    #[cfg(target_family = "wasm")]
+    pub network_info_cache_ttl: Duration,
 }
 
 fn default_api_timeout() -> Duration {
     DEFAULT_API_TIMEOUT
 }
 
+#[cfg(target_family = "wasm")]
+fn default_network_info_cache_ttl() -> Duration {
+    DEFAULT_NETWORK_INFO_CACHE_TTL
+}
+
 fn default_remote_pow_timeout() -> Duration {
     DEFAULT_REMOTE_POW_API_TIMEOUT
 }
@@ -162,6 +175,8 @@ impl Default for ClientBuilder {
             remote_pow_timeout: DEFAULT_REMOTE_POW_API_TIMEOUT,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count: None,
+            #[cfg(target_family = "wasm")]
+            network_info_cache_ttl: DEFAULT_NETWORK_INFO_CACHE_TTL,
         }
     }
 }
@@ -305,6 +320,15 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets how long the network info is cached before it's requested from the node again. Only available in WASM,
+    /// outside of it the network info is updated with every node sync, see
+    /// [`with_node_sync_interval()`](Self::with_node_sync_interval).
+    #[cfg(target_family = "wasm")]
+    pub fn with_network_info_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.network_info_cache_ttl = ttl;
+        self
+    }
+
     /// Sets the default request timeout.
     pub fn with_api_timeout(mut self, timeout: Duration) -> Self {
         self.api_timeout = timeout;
@@ -389,6 +413,8 @@ impl ClientBuilder {
                 network_info: RwLock::new(self.network_info),
                 api_timeout: RwLock::new(self.api_timeout),
                 remote_pow_timeout: RwLock::new(self.remote_pow_timeout),
+                network_info_cache_ttl: RwLock::new(self.network_info_cache_ttl),
+                network_info_cache_expiry: RwLock::new(None),
                 #[cfg(feature = "mqtt")]
                 mqtt: super::MqttInner {
                     client: Default::default(),
@@ -413,6 +439,8 @@ impl ClientBuilder {
             remote_pow_timeout: client.get_remote_pow_timeout().await,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count: *client.pow_worker_count.read().await,
+            #[cfg(target_family = "wasm")]
+            network_info_cache_ttl: *client.network_info_cache_ttl.read().await,
         }
     }
 }
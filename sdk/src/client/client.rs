@@ -13,8 +13,6 @@ use {
     tokio::sync::watch::{Receiver as WatchReceiver, Sender as WatchSender},
 };
 
-#[cfg(target_family = "wasm")]
-use crate::client::constants::CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS;
 use crate::{
     client::{
         builder::{ClientBuilder, NetworkInfo},
@@ -52,6 +50,12 @@ pub struct ClientInner {
     /// pow_worker_count for local PoW.
     #[cfg(not(target_family = "wasm"))]
     pub(crate) pow_worker_count: RwLock<Option<usize>>,
+    /// How long the network info is cached before it's requested from the node again.
+    #[cfg(target_family = "wasm")]
+    pub(crate) network_info_cache_ttl: RwLock<Duration>,
+    /// Unix timestamp in seconds until which the cached network info is used.
+    #[cfg(target_family = "wasm")]
+    pub(crate) network_info_cache_expiry: RwLock<Option<u64>>,
     #[cfg(feature = "mqtt")]
     pub(crate) mqtt: MqttInner,
 }
@@ -100,16 +104,14 @@ impl ClientInner {
     /// and if it's the default one, sync it first and set the NetworkInfo.
     pub async fn get_network_info(&self) -> Result<NetworkInfo> {
         // For WASM we don't have the node syncing process, which updates the network_info every 60 seconds, but the Pow
-        // difficulty or the byte cost could change via a milestone, so we request the node info every time, so we don't
-        // create invalid transactions/blocks.
+        // difficulty or the byte cost could change via a milestone, so we request the node info again once the cached
+        // one is older than `network_info_cache_ttl` or the nodes changed, so we don't create invalid
+        // transactions/blocks.
         #[cfg(target_family = "wasm")]
         {
-            lazy_static::lazy_static! {
-                static ref LAST_SYNC: std::sync::Mutex<Option<u32>> = std::sync::Mutex::new(None);
-            };
-            let current_time = crate::utils::unix_timestamp_now().as_secs() as u32;
-            if let Some(last_sync) = *LAST_SYNC.lock().unwrap() {
-                if current_time < last_sync {
+            let current_time = crate::utils::unix_timestamp_now().as_secs();
+            if let Some(expiry) = *self.network_info_cache_expiry.read().await {
+                if current_time < expiry {
                     return Ok(self.network_info.read().await.clone());
                 }
             }
@@ -117,7 +119,8 @@ impl ClientInner {
             let mut client_network_info = self.network_info.write().await;
             client_network_info.protocol_parameters = info.protocol.try_into()?;
 
-            *LAST_SYNC.lock().unwrap() = Some(current_time + CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS);
+            *self.network_info_cache_expiry.write().await =
+                Some(current_time + self.network_info_cache_ttl.read().await.as_secs());
         }
 
         Ok(self.network_info.read().await.clone())
@@ -22,9 +22,9 @@ pub(crate) const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/",
 pub(crate) const MAX_PARALLEL_API_REQUESTS: usize = 100;
 /// Max allowed difference between the local time and latest milestone time, 5 minutes in seconds
 pub(crate) const FIVE_MINUTES_IN_SECONDS: u32 = 300;
-/// Delay for caching a node info response in WASM runtime
+/// Default duration for caching a node info response in WASM runtime
 #[cfg(target_family = "wasm")]
-pub(crate) const CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS: u32 = 60;
+pub(crate) const DEFAULT_NETWORK_INFO_CACHE_TTL: Duration = Duration::from_secs(60);
 
 /// Bech32 hrp for the IOTA mainnet <https://github.com/satoshilabs/slips/blob/master/slip-0173.md>
 pub const IOTA_BECH32_HRP: &str = "iota";
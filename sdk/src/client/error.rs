@@ -59,6 +59,9 @@ pub enum Error {
         /// The expected bech32 human readable part.
         expected: String,
     },
+    /// Invalid amount or amount conversion
+    #[error("invalid amount conversion: {0}")]
+    InvalidAmountConversion(String),
     /// Invalid mnemonic error
     #[error("invalid mnemonic {0}")]
     InvalidMnemonic(String),
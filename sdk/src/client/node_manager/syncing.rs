@@ -156,6 +156,8 @@ impl Client {
     #[cfg(target_family = "wasm")]
     pub async fn update_node_manager(&self, node_manager: NodeManager) -> crate::wallet::Result<()> {
         *self.node_manager.write().await = node_manager;
+        // The new nodes could be on another network
+        *self.network_info_cache_expiry.write().await = None;
         Ok(())
     }
 }
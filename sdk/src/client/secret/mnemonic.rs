@@ -37,33 +37,10 @@ impl SecretManage for MnemonicSecretManager {
         options: Option<GenerateAddressOptions>,
     ) -> Result<Vec<Address>, Self::Error> {
         let internal = options.map(|o| o.internal).unwrap_or_default();
-        let mut addresses = Vec::new();
-
-        for address_index in address_indexes {
-            let chain = Chain::from_u32_hardened(vec![
-                HD_WALLET_TYPE,
-                coin_type,
-                account_index,
-                internal as u32,
-                address_index,
-            ]);
-
-            let public_key = self
-                .0
-                .derive(Curve::Ed25519, &chain)?
-                .secret_key()
-                .public_key()
-                .to_bytes();
-
-            // Hash the public key to get the address
-            let result = Blake2b256::digest(public_key).try_into().map_err(|_e| {
-                crate::client::Error::Blake2b256("hashing the public key while generating the address failed.")
-            });
-
-            addresses.push(Address::Ed25519(Ed25519Address::new(result?)));
-        }
-
-        Ok(addresses)
+
+        address_indexes
+            .map(|address_index| self.generate_address(coin_type, account_index, internal, address_index))
+            .collect()
     }
 
     async fn sign_ed25519(&self, msg: &[u8], chain: &Chain) -> Result<Ed25519Signature, Self::Error> {
@@ -89,6 +66,38 @@ impl MnemonicSecretManager {
         let bytes: Vec<u8> = prefix_hex::decode(hex)?;
         Ok(Self(Seed::from_bytes(&bytes)))
     }
+
+    /// Generates a single ed25519 address for the BIP-44 path
+    /// `m/44'/coin_type'/account_index'/internal'/address_index'` without requiring an async context.
+    pub fn generate_address(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        internal: bool,
+        address_index: u32,
+    ) -> Result<Address, Error> {
+        let chain = Chain::from_u32_hardened(vec![
+            HD_WALLET_TYPE,
+            coin_type,
+            account_index,
+            internal as u32,
+            address_index,
+        ]);
+
+        let public_key = self
+            .0
+            .derive(Curve::Ed25519, &chain)?
+            .secret_key()
+            .public_key()
+            .to_bytes();
+
+        // Hash the public key to get the address
+        let result = Blake2b256::digest(public_key).try_into().map_err(|_e| {
+            crate::client::Error::Blake2b256("hashing the public key while generating the address failed.")
+        });
+
+        Ok(Address::Ed25519(Ed25519Address::new(result?)))
+    }
 }
 
 #[cfg(test)]
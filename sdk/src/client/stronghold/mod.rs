@@ -54,7 +54,7 @@ mod storage;
 use std::{
     path::{Path, PathBuf},
     sync::Arc,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use derive_builder::Builder;
@@ -107,6 +107,10 @@ pub struct StrongholdAdapter {
     #[builder(setter(custom))]
     timeout_task: Arc<Mutex<Option<JoinHandle<()>>>>,
 
+    /// The instant at which the timeout task clears the key.
+    #[builder(setter(skip))]
+    key_clear_deadline: Arc<Mutex<Option<Instant>>>,
+
     /// The path to a Stronghold snapshot file.
     #[builder(setter(skip))]
     pub snapshot_path: PathBuf,
@@ -174,6 +178,7 @@ impl StrongholdAdapterBuilder {
         let has_key_provider = self.key_provider.is_some();
         let key_provider = Arc::new(Mutex::new(self.key_provider));
         let stronghold = Arc::new(Mutex::new(stronghold));
+        let mut key_clear_deadline = None;
 
         // If both `key` and `timeout` are set, then we spawn the task and keep its join handle.
         if let (true, Some(Some(timeout))) = (has_key_provider, self.timeout) {
@@ -199,6 +204,7 @@ impl StrongholdAdapterBuilder {
 
             // Keep the task handle in the builder; the code below checks this.
             self.timeout_task = Some(timeout_task);
+            key_clear_deadline = Some(Instant::now() + timeout);
         }
 
         // Create the adapter as per configuration and return it.
@@ -207,6 +213,7 @@ impl StrongholdAdapterBuilder {
             key_provider,
             timeout: self.timeout.unwrap_or(None),
             timeout_task: self.timeout_task.unwrap_or_else(|| Arc::new(Mutex::new(None))),
+            key_clear_deadline: Arc::new(Mutex::new(key_clear_deadline)),
             snapshot_path: snapshot_path.as_ref().to_path_buf(),
         })
     }
@@ -265,6 +272,7 @@ impl StrongholdAdapter {
                 key_provider,
                 timeout,
             )));
+            *self.key_clear_deadline.lock().await = Some(Instant::now() + timeout);
         }
 
         Ok(())
@@ -320,6 +328,7 @@ impl StrongholdAdapter {
                             key_provider,
                             timeout,
                         )));
+                        *self.key_clear_deadline.lock().await = Some(Instant::now() + timeout);
                     }
 
                     return Err(err);
@@ -363,6 +372,7 @@ impl StrongholdAdapter {
                         key_provider,
                         timeout,
                     )));
+                    *self.key_clear_deadline.lock().await = Some(Instant::now() + timeout);
                 }
 
                 return Err(err);
@@ -384,6 +394,7 @@ impl StrongholdAdapter {
                 key_provider,
                 timeout,
             )));
+            *self.key_clear_deadline.lock().await = Some(Instant::now() + timeout);
         }
 
         Ok(())
@@ -411,6 +422,18 @@ impl StrongholdAdapter {
         debug!("cleared stronghold key");
     }
 
+    /// Get the time left until the key clearing task clears the key. Returns `None` if the key isn't available or no
+    /// key clearing task is running.
+    pub async fn get_key_clear_countdown(&self) -> Option<Duration> {
+        if self.timeout_task.lock().await.is_none() || !self.is_key_available().await {
+            return None;
+        }
+        self.key_clear_deadline
+            .lock()
+            .await
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
     /// Get timeout for the key clearing task.
     pub fn get_timeout(&self) -> Option<Duration> {
         self.timeout
@@ -444,6 +467,7 @@ impl StrongholdAdapter {
                 key_provider,
                 timeout,
             )));
+            *self.key_clear_deadline.lock().await = Some(Instant::now() + timeout);
         }
     }
 
@@ -566,12 +590,14 @@ mod tests {
         assert!(matches!(*adapter.key_provider.lock().await, Some(_)));
         assert_eq!(adapter.get_timeout(), Some(timeout));
         assert!(matches!(*adapter.timeout_task.lock().await, Some(_)));
+        assert!(matches!(adapter.get_key_clear_countdown().await, Some(countdown) if countdown <= timeout));
 
         // After the timeout, the key should be purged.
         tokio::time::sleep(Duration::from_millis(200)).await;
         assert!(matches!(*adapter.key_provider.lock().await, None));
         assert_eq!(adapter.get_timeout(), Some(timeout));
         assert!(matches!(*adapter.timeout_task.lock().await, None));
+        assert_eq!(adapter.get_key_clear_countdown().await, None);
 
         // Set the key again, but this time we manually purge the key.
         let timeout = None;
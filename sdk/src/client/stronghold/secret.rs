@@ -230,13 +230,7 @@ impl StrongholdAdapter {
             .map_err(|e| Error::InvalidMnemonic(format!("{e:?}")))?;
 
         // We need to check if there has been a mnemonic stored in Stronghold or not to prevent overwriting it.
-        if self
-            .stronghold
-            .lock()
-            .await
-            .get_client(PRIVATE_DATA_CLIENT_PATH)?
-            .record_exists(&output)?
-        {
+        if self.is_mnemonic_stored().await? {
             return Err(Error::MnemonicAlreadyStored);
         }
 
@@ -248,6 +242,16 @@ impl StrongholdAdapter {
 
         Ok(())
     }
+
+    /// Check if a mnemonic has been stored in the Stronghold vault.
+    pub async fn is_mnemonic_stored(&self) -> Result<bool, Error> {
+        Ok(self
+            .stronghold
+            .lock()
+            .await
+            .get_client(PRIVATE_DATA_CLIENT_PATH)?
+            .record_exists(&Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH))?)
+    }
 }
 
 #[cfg(test)]
@@ -10,6 +10,7 @@ use crypto::{
     keys::{bip39::wordlist, slip10::Seed},
     utils,
 };
+use serde::{Deserialize, Serialize};
 use zeroize::Zeroize;
 
 use super::{Client, ClientInner};
@@ -84,11 +85,92 @@ pub fn mnemonic_to_seed(mnemonic: &str) -> Result<Seed> {
 
 /// Verifies that a &str is a valid mnemonic.
 pub fn verify_mnemonic(mnemonic: &str) -> Result<()> {
+    // the wordlist only contains lowercase words separated by single spaces, reject anything else with a clear error
+    if mnemonic.trim() != mnemonic {
+        return Err(Error::InvalidMnemonic("leading or trailing whitespace".to_string()));
+    }
+    if mnemonic.chars().any(char::is_uppercase) {
+        return Err(Error::InvalidMnemonic("words need to be lowercase".to_string()));
+    }
     crypto::keys::bip39::wordlist::verify(mnemonic, &crypto::keys::bip39::wordlist::ENGLISH)
         .map_err(|e| crate::client::Error::InvalidMnemonic(format!("{e:?}")))?;
     Ok(())
 }
 
+/// Units in which base token amounts can be expressed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum AmountUnit {
+    /// The smallest Shimmer unit.
+    Glow,
+    /// 1 SMR = 1_000_000 glow.
+    Smr,
+    /// The smallest IOTA unit.
+    I,
+    /// 1 Ki = 1_000 i.
+    Ki,
+    /// 1 Mi = 1_000_000 i.
+    Mi,
+    /// 1 Gi = 1_000_000_000 i.
+    Gi,
+    /// 1 Ti = 1_000_000_000_000 i.
+    Ti,
+    /// 1 Pi = 1_000_000_000_000_000 i.
+    Pi,
+}
+
+impl AmountUnit {
+    /// Returns the number of decimals of the unit, the smallest unit is worth `10^-decimals` of it.
+    pub const fn decimals(&self) -> u32 {
+        match self {
+            Self::Glow | Self::I => 0,
+            Self::Ki => 3,
+            Self::Smr | Self::Mi => 6,
+            Self::Gi => 9,
+            Self::Ti => 12,
+            Self::Pi => 15,
+        }
+    }
+}
+
+/// Converts a decimal amount between units without floating point arithmetic.
+/// Fails if the amount isn't a plain decimal number, overflows or isn't a whole number of the smallest unit.
+pub fn convert_amount(amount: &str, from: AmountUnit, to: AmountUnit) -> Result<String> {
+    let invalid = |reason: &str| Error::InvalidAmountConversion(format!("{amount}: {reason}"));
+
+    let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if integer.is_empty()
+        || (amount.contains('.') && fraction.is_empty())
+        || !integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid("not a decimal number"));
+    }
+
+    let from_decimals = from.decimals() as usize;
+    // Digits beyond the decimals of the unit would be a fraction of the smallest unit
+    let (fraction, sub_smallest_unit) = fraction.split_at(fraction.len().min(from_decimals));
+    if sub_smallest_unit.chars().any(|c| c != '0') {
+        return Err(invalid("fractions of the smallest unit are not possible"));
+    }
+
+    // Amount in the smallest unit
+    let smallest_unit_amount = format!("{integer}{fraction:0<from_decimals$}")
+        .parse::<u128>()
+        .map_err(|_| invalid("overflow"))?;
+
+    let to_multiplier = 10u128.pow(to.decimals());
+    let (integer, fraction) = (
+        smallest_unit_amount / to_multiplier,
+        smallest_unit_amount % to_multiplier,
+    );
+
+    if fraction == 0 {
+        Ok(integer.to_string())
+    } else {
+        let fraction = format!("{fraction:0>width$}", width = to.decimals() as usize);
+        Ok(format!("{integer}.{}", fraction.trim_end_matches('0')))
+    }
+}
+
 /// Requests funds from a faucet
 pub async fn request_funds_from_faucet(url: &str, bech32_address: &str) -> Result<String> {
     let mut map = HashMap::new();
@@ -160,6 +242,11 @@ impl Client {
         generate_mnemonic()
     }
 
+    /// Converts a decimal amount between units.
+    pub fn convert_amount(amount: &str, from: AmountUnit, to: AmountUnit) -> Result<String> {
+        convert_amount(amount, from, to)
+    }
+
     /// Returns a seed for a mnemonic.
     pub fn mnemonic_to_seed(mnemonic: &str) -> Result<Seed> {
         mnemonic_to_seed(mnemonic)
@@ -10,7 +10,7 @@ use alloc::{string::String, vec::Vec};
 
 use ::bech32::{FromBase32, ToBase32, Variant};
 use derive_more::From;
-use packable::PackableExt;
+use packable::{error::UnpackError, PackableExt};
 
 pub use self::{alias::AliasAddress, bech32::Bech32Address, ed25519::Ed25519Address, nft::NftAddress};
 use crate::types::block::{
@@ -114,15 +114,16 @@ impl Address {
 
     /// Tries to create an [`Address`] from a bech32 encoded string, also returns the HRP.
     pub fn try_from_bech32_with_hrp<T: AsRef<str>>(address: T) -> Result<(String, Self), Error> {
-        match ::bech32::decode(address.as_ref()) {
-            Ok((hrp, data, _)) => {
-                let bytes = Vec::<u8>::from_base32(&data).map_err(|_| Error::InvalidAddress)?;
-                Self::unpack_verified(bytes.as_slice(), &())
-                    .map_err(|_| Error::InvalidAddress)
-                    .map(|address| (hrp, address))
-            }
-            Err(_) => Err(Error::InvalidAddress),
-        }
+        let (hrp, data, _) = ::bech32::decode(address.as_ref()).map_err(Error::InvalidBech32)?;
+        let bytes = Vec::<u8>::from_base32(&data).map_err(Error::InvalidBech32)?;
+
+        Self::unpack_verified(bytes.as_slice(), &())
+            .map_err(|e| match e {
+                // keep the reason for known errors like an unknown address kind
+                UnpackError::Packable(e) => e,
+                UnpackError::Unpacker(_) => Error::InvalidAddress,
+            })
+            .map(|address| (hrp, address))
     }
 
     /// Encodes this address to a bech32 string with the given Human Readable Part as prefix.
@@ -54,6 +54,7 @@ pub enum Error {
     InvalidInputKind(u8),
     InvalidInputCount(<InputCount as TryFrom<usize>>::Error),
     InvalidInputOutputIndex(<OutputIndex as TryFrom<u16>>::Error),
+    InvalidBech32(::bech32::Error),
     InvalidBech32Hrp(FromUtf8Error),
     InvalidBlockLength(usize),
     InvalidStateMetadataLength(<StateMetadataLength as TryFrom<usize>>::Error),
@@ -161,6 +162,7 @@ impl fmt::Display for Error {
             Self::InvalidAddress => write!(f, "invalid address provided"),
             Self::InvalidAddressKind(k) => write!(f, "invalid address kind: {k}"),
             Self::InvalidAliasIndex(index) => write!(f, "invalid alias index: {index}"),
+            Self::InvalidBech32(err) => write!(f, "invalid bech32: {err}"),
             Self::InvalidBech32Hrp(err) => write!(f, "invalid bech32 hrp: {err}"),
             Self::InvalidBinaryParametersLength(length) => {
                 write!(f, "invalid binary parameters length: {length}")
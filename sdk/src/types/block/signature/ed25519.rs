@@ -42,6 +42,12 @@ impl Ed25519Signature {
         &self.signature
     }
 
+    /// Verifies the [`Ed25519Signature`] for a message with its public key, without checking any address.
+    /// Returns an error if the public key is malformed and `false` if the signature doesn't match the message.
+    pub fn verify(&self, message: &[u8]) -> Result<bool, Error> {
+        Ok(PublicKey::try_from_bytes(self.public_key)?.verify(&Signature::from_bytes(self.signature), message))
+    }
+
     /// Verifies the [`Ed25519Signature`] for a message against an [`Ed25519Address`].
     pub fn is_valid(&self, message: &[u8], address: &Ed25519Address) -> Result<(), Error> {
         let signature_address: [u8; PUBLIC_KEY_LENGTH] = Blake2b256::digest(self.public_key).into();
@@ -53,7 +59,7 @@ impl Ed25519Signature {
             });
         }
 
-        if !PublicKey::try_from_bytes(self.public_key)?.verify(&Signature::from_bytes(self.signature), message) {
+        if !self.verify(message)? {
             return Err(Error::InvalidSignature);
         }
 
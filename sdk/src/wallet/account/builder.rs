@@ -19,6 +19,7 @@ pub struct AccountBuilder {
     addresses: Option<Vec<AccountAddress>>,
     alias: Option<String>,
     bech32_hrp: Option<String>,
+    watch_only: bool,
     wallet: Wallet,
 }
 
@@ -29,6 +30,7 @@ impl AccountBuilder {
             addresses: None,
             alias: None,
             bech32_hrp: None,
+            watch_only: false,
             wallet,
         }
     }
@@ -40,6 +42,25 @@ impl AccountBuilder {
         self
     }
 
+    /// Create a watch-only account that tracks the provided addresses, it can be synced, but can't sign or generate
+    /// addresses, so no secret manager is needed
+    pub fn with_watch_only_addresses(mut self, addresses: Vec<Bech32Address>) -> Self {
+        self.addresses = Some(
+            addresses
+                .into_iter()
+                .zip(0..)
+                .map(|(address, key_index)| AccountAddress {
+                    address,
+                    key_index,
+                    internal: false,
+                    used: false,
+                })
+                .collect(),
+        );
+        self.watch_only = true;
+        self
+    }
+
     /// Set the alias
     pub fn with_alias(mut self, alias: impl Into<Option<String>>) -> Self {
         self.alias = alias.into();
@@ -57,7 +78,11 @@ impl AccountBuilder {
     /// account will also be generated and compared, so no accounts get generated with different seeds
     pub async fn finish(&mut self) -> crate::wallet::Result<Account> {
         let mut accounts = self.wallet.accounts.write().await;
-        let account_index = accounts.len() as u32;
+        // Use the index after the highest existing one, accounts can be removed, so there can be gaps
+        let mut account_index = 0;
+        for account in accounts.iter() {
+            account_index = account_index.max(*account.details().await.index() + 1);
+        }
         // If no alias is provided, the account index will be set as alias
         let account_alias = self.alias.clone().unwrap_or_else(|| account_index.to_string());
         log::debug!(
@@ -68,6 +93,11 @@ impl AccountBuilder {
 
         let coin_type = self.wallet.coin_type.load(core::sync::atomic::Ordering::Relaxed);
 
+        // Watch-only accounts can't generate addresses, so there must be at least one to track
+        if self.watch_only && self.addresses.as_ref().map_or(true, |addresses| addresses.is_empty()) {
+            return Err(Error::WatchOnlyAccount(account_index));
+        }
+
         // Check that the alias isn't already used for another account and that the coin type is the same for new and
         // existing accounts
         for account in accounts.iter() {
@@ -90,13 +120,29 @@ impl AccountBuilder {
             Some(addresses) => addresses.clone(),
             None => {
                 let mut bech32_hrp = self.bech32_hrp.clone();
-                if let Some(first_account) = accounts.first() {
-                    let first_account_coin_type = *first_account.details().await.coin_type();
+                // Watch-only accounts weren't created from the seed of the secret manager, so they're skipped
+                let mut first_account = None;
+                for account in accounts.iter() {
+                    if !account.details().await.watch_only {
+                        first_account = Some(account);
+                        break;
+                    }
+                }
+                if let Some(first_account) = first_account {
+                    let (first_account_coin_type, first_account_index) = {
+                        let first_account = first_account.details().await;
+                        (*first_account.coin_type(), *first_account.index())
+                    };
                     // Generate the first address of the first account and compare it to the stored address from the
                     // first account to prevent having multiple accounts created with different
-                    // seeds
-                    let first_account_public_address =
-                        get_first_public_address(&self.wallet.secret_manager, first_account_coin_type, 0).await?;
+                    // seeds, the first account doesn't need to have index 0 if accounts were removed or only some were
+                    // restored from a backup
+                    let first_account_public_address = get_first_public_address(
+                        &self.wallet.secret_manager,
+                        first_account_coin_type,
+                        first_account_index,
+                    )
+                    .await?;
                     let first_account_addresses = first_account.public_addresses().await;
 
                     if first_account_public_address
@@ -156,6 +202,8 @@ impl AccountBuilder {
             incoming_transactions: HashMap::new(),
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            watch_only: self.watch_only,
+            transaction_notes: HashMap::new(),
         };
 
         let account = Account::new(account, self.wallet.inner.clone()).await?;
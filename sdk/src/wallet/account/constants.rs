@@ -11,9 +11,20 @@ pub(crate) const DEFAULT_LEDGER_OUTPUT_CONSOLIDATION_THRESHOLD: usize = 15;
 /// Amount of API request that can be sent in parallel during syncing
 pub(crate) const PARALLEL_REQUESTS_AMOUNT: usize = 500;
 
+/// Amount of accounts that get synced in parallel when syncing all accounts of a wallet
+pub(crate) const PARALLEL_ACCOUNT_SYNCS_AMOUNT: usize = 5;
+
+/// Minimum time between two sync progress events, so fast syncs don't flood the event listeners
+#[cfg(feature = "events")]
+pub(crate) const SYNC_PROGRESS_EVENT_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
 /// ms before an account actually syncs with the network, before it just returns the previous syncing result
 /// this is done to prevent unnecessary simultaneous synchronizations
 pub(crate) const MIN_SYNC_INTERVAL: u128 = 5;
 
+/// Amount of not yet generated public and internal addresses that are checked when looking for an address of the
+/// account
+pub(crate) const DEFAULT_ADDRESS_GAP_LIMIT: u32 = 20;
+
 // Default expiration time for [ExpirationUnlockCondition] when sending native tokens, one day in seconds
 pub(crate) const DEFAULT_EXPIRATION_TIME: u32 = 86400;
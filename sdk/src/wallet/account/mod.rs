@@ -16,7 +16,7 @@ use std::{
     collections::{HashMap, HashSet},
     ops::Deref,
     str::FromStr,
-    sync::Arc,
+    sync::{atomic::AtomicUsize, Arc},
 };
 
 use getset::{Getters, Setters};
@@ -32,9 +32,13 @@ use self::types::{
 pub use self::{
     operations::{
         output_claiming::OutputsToClaim,
+        retry::{
+            RetryOptions, DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL, DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT,
+            MIN_RETRY_UNTIL_INCLUDED_INTERVAL,
+        },
         syncing::{
             options::{AccountSyncOptions, AliasSyncOptions, NftSyncOptions},
-            SyncOptions,
+            SyncOptions, SyncStatus,
         },
         transaction::{
             high_level::{
@@ -54,10 +58,14 @@ pub use self::{
 };
 use super::wallet::WalletInner;
 use crate::{
-    client::Client,
+    client::{
+        secret::{GenerateAddressOptions, SecretManage, SecretManager},
+        Client,
+    },
     types::{
-        api::core::response::OutputWithMetadataResponse,
+        api::core::{dto::LedgerInclusionStateDto, response::OutputWithMetadataResponse},
         block::{
+            address::{Address, Bech32Address},
             output::{AliasId, FoundryId, FoundryOutput, NftId, Output, OutputId, TokenId},
             payload::{
                 transaction::{TransactionEssence, TransactionId},
@@ -66,7 +74,10 @@ use crate::{
             BlockId,
         },
     },
-    wallet::{account::types::InclusionState, Result},
+    wallet::{
+        account::{constants::DEFAULT_ADDRESS_GAP_LIMIT, types::InclusionState},
+        Result,
+    },
 };
 
 /// Options to filter outputs
@@ -85,6 +96,10 @@ pub struct FilterOptions {
     pub foundry_ids: Option<HashSet<FoundryId>>,
     /// Return all nft outputs matching these IDs.
     pub nft_ids: Option<HashSet<NftId>>,
+    /// Filter all outputs with an amount smaller than the specified one, the bound is exclusive.
+    pub amount_smaller_than: Option<u64>,
+    /// Filter all outputs with an amount greater than the specified one, the bound is exclusive.
+    pub amount_greater_than: Option<u64>,
 }
 
 /// Details of an account.
@@ -93,6 +108,7 @@ pub struct FilterOptions {
 #[serde(rename_all = "camelCase")]
 pub struct AccountDetails {
     /// The account index
+    #[getset(set = "pub(crate)")]
     index: u32,
     /// The coin type
     coin_type: u32,
@@ -134,6 +150,13 @@ pub struct AccountDetails {
     /// Foundries for native tokens in outputs
     #[serde(default)]
     native_token_foundries: HashMap<FoundryId, FoundryOutput>,
+    /// Watch-only accounts were imported without secret material, so they can't sign or generate addresses
+    #[serde(default)]
+    #[getset(set = "pub(crate)")]
+    watch_only: bool,
+    /// Local notes for sent and incoming transactions, they're never sent to the network
+    #[serde(default)]
+    transaction_notes: HashMap<TransactionId, String>,
 }
 
 /// A thread guard over an account, so we can lock the account during operations.
@@ -150,7 +173,12 @@ pub struct AccountInner {
     // if the last synced time was < `MIN_SYNC_INTERVAL` second ago, we don't sync, but only calculate the balance
     // again, because sending transactions can change that
     pub(crate) last_synced: Mutex<u128>,
+    // the latest milestone index of the node when the last sync started, everything up to it is synced
+    pub(crate) synced_milestone_index: Mutex<Option<u32>>,
+    // number of pending abort requests for this account, its syncs abort while it's not 0
+    pub(crate) sync_abort_requests: AtomicUsize,
     pub(crate) default_sync_options: Mutex<SyncOptions>,
+    pub(crate) default_retry_options: Mutex<RetryOptions>,
 }
 
 // impl Deref so we can use `account.details()` instead of `account.details.read()`
@@ -165,23 +193,34 @@ impl Deref for Account {
 impl Account {
     /// Create a new Account with an AccountDetails
     pub(crate) async fn new(details: AccountDetails, wallet: Arc<WalletInner>) -> Result<Self> {
+        // Accounts without their own default sync options use the ones of the wallet
+        let wallet_default_sync_options = wallet.default_sync_options.lock().await.clone();
         #[cfg(feature = "storage")]
-        let default_sync_options = wallet
-            .storage_manager
-            .read()
-            .await
-            .get_default_sync_options(*details.index())
-            .await?
-            .unwrap_or_default();
+        let (default_sync_options, default_retry_options) = {
+            let storage_manager = wallet.storage_manager.read().await;
+            (
+                storage_manager
+                    .get_default_sync_options(*details.index())
+                    .await?
+                    .unwrap_or(wallet_default_sync_options),
+                storage_manager
+                    .get_default_retry_options(*details.index())
+                    .await?
+                    .unwrap_or_default(),
+            )
+        };
         #[cfg(not(feature = "storage"))]
-        let default_sync_options = Default::default();
+        let (default_sync_options, default_retry_options) = (wallet_default_sync_options, Default::default());
 
         Ok(Self {
             wallet,
             inner: Arc::new(AccountInner {
                 details: RwLock::new(details),
                 last_synced: Default::default(),
+                synced_milestone_index: Default::default(),
+                sync_abort_requests: AtomicUsize::new(0),
                 default_sync_options: Mutex::new(default_sync_options),
+                default_retry_options: Mutex::new(default_retry_options),
             }),
         })
     }
@@ -211,6 +250,76 @@ impl Account {
         Ok(output_response.output().to_owned())
     }
 
+    /// Get the [`InclusionState`] of a transaction stored in the account. For pending transactions the node is asked
+    /// for the current state, without updating the stored transaction, which happens during syncing.
+    pub async fn get_transaction_inclusion_state(&self, transaction_id: &TransactionId) -> Result<InclusionState> {
+        let transaction = self
+            .get_transaction(transaction_id)
+            .await
+            .ok_or(crate::wallet::Error::TransactionNotFound(*transaction_id))?;
+
+        if transaction.inclusion_state != InclusionState::Pending {
+            return Ok(transaction.inclusion_state);
+        }
+
+        Ok(self
+            .request_inclusion_state(&transaction)
+            .await?
+            .unwrap_or(InclusionState::Pending))
+    }
+
+    /// Get the [`InclusionState`] of all pending transactions of the account from the node, together with the number
+    /// of times they got reattached. Transactions whose block isn't known by the node anymore, because it got pruned,
+    /// are reported as [`InclusionState::UnknownPruned`]. The stored transactions are updated during syncing.
+    pub async fn pending_transactions_status(&self) -> Result<Vec<(TransactionId, InclusionState, u32)>> {
+        let pending_transactions = self.pending_transactions().await;
+        let mut statuses = Vec::with_capacity(pending_transactions.len());
+
+        for transaction in pending_transactions {
+            let inclusion_state = self
+                .request_inclusion_state(&transaction)
+                .await?
+                .unwrap_or(InclusionState::UnknownPruned);
+            statuses.push((transaction.transaction_id, inclusion_state, transaction.reattachments));
+        }
+
+        Ok(statuses)
+    }
+
+    // Request the inclusion state of a transaction from the node, returns `None` if the block of the transaction isn't
+    // known by the node
+    async fn request_inclusion_state(&self, transaction: &Transaction) -> Result<Option<InclusionState>> {
+        match self
+            .client()
+            .get_included_block_metadata(&transaction.transaction_id)
+            .await
+        {
+            Ok(metadata) => Ok(Some(match metadata.ledger_inclusion_state {
+                Some(LedgerInclusionStateDto::Included) => InclusionState::Confirmed,
+                Some(LedgerInclusionStateDto::Conflicting) => InclusionState::Conflicting,
+                _ => InclusionState::Pending,
+            })),
+            Err(crate::client::Error::Node(crate::client::node_api::error::Error::NotFound(_))) => {
+                // Not included yet, but the attachment might already be conflicting
+                if let Some(block_id) = transaction.block_id {
+                    match self.client().get_block_metadata(&block_id).await {
+                        Ok(metadata) => {
+                            if let Some(LedgerInclusionStateDto::Conflicting) = metadata.ledger_inclusion_state {
+                                return Ok(Some(InclusionState::Conflicting));
+                            }
+                        }
+                        Err(crate::client::Error::Node(crate::client::node_api::error::Error::NotFound(_))) => {
+                            return Ok(None);
+                        }
+                        Err(e) => return Err(e.into()),
+                    }
+                }
+                Ok(Some(InclusionState::Pending))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
     /// Save the account to the database, accepts the updated_account as option so we don't need to drop it before
     /// saving
     #[cfg(feature = "storage")]
@@ -262,6 +371,11 @@ impl AccountInner {
         self.details().await.transactions().get(transaction_id).cloned()
     }
 
+    /// Get the local note of a transaction stored in the account
+    pub async fn get_transaction_note(&self, transaction_id: &TransactionId) -> Option<String> {
+        self.details().await.transaction_notes().get(transaction_id).cloned()
+    }
+
     /// Get the transaction with inputs of an incoming transaction stored in the account
     /// List might not be complete, if the node pruned the data already
     pub async fn get_incoming_transaction(&self, transaction_id: &TransactionId) -> Option<Transaction> {
@@ -280,6 +394,58 @@ impl AccountInner {
         Ok(all_addresses.to_vec())
     }
 
+    /// Returns whether the address belongs to the account. All generated public and internal addresses are checked,
+    /// including the ones that were never used. Unless the account is watch-only, the next `address_gap_limit` public
+    /// and internal addresses that weren't generated yet are also checked, by default 20.
+    pub async fn is_address_mine(
+        &self,
+        address: &Bech32Address,
+        address_gap_limit: impl Into<Option<u32>> + Send,
+    ) -> Result<bool> {
+        let account_details = self.details().await;
+        if account_details
+            .public_addresses()
+            .iter()
+            .chain(account_details.internal_addresses())
+            .any(|account_address| account_address.address().inner() == address.inner())
+        {
+            return Ok(true);
+        }
+
+        let address_gap_limit = address_gap_limit.into().unwrap_or(DEFAULT_ADDRESS_GAP_LIMIT);
+        if account_details.watch_only || address_gap_limit == 0 {
+            return Ok(false);
+        }
+        let coin_type = account_details.coin_type;
+        let account_index = account_details.index;
+        let next_public_index = account_details.public_addresses.len() as u32;
+        let next_internal_index = account_details.internal_addresses.len() as u32;
+        drop(account_details);
+
+        let secret_manager = self.wallet.secret_manager.read().await;
+        if matches!(*secret_manager, SecretManager::Placeholder(_)) {
+            return Ok(false);
+        }
+        for (next_index, options) in [
+            (next_public_index, GenerateAddressOptions::default()),
+            (next_internal_index, GenerateAddressOptions::internal()),
+        ] {
+            let gap_addresses = secret_manager
+                .generate_addresses(
+                    coin_type,
+                    account_index,
+                    next_index..next_index + address_gap_limit,
+                    Some(options),
+                )
+                .await?;
+            if gap_addresses.contains(address.inner()) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
     /// Returns all public addresses of the account
     pub(crate) async fn public_addresses(&self) -> Vec<AccountAddress> {
         self.details().await.public_addresses().to_vec()
@@ -290,6 +456,12 @@ impl AccountInner {
         Ok(self.details().await.addresses_with_unspent_outputs().to_vec())
     }
 
+    /// Returns only addresses of the account that never had an output, spent or unspent, based on the known outputs
+    /// and the transaction history
+    pub async fn unused_addresses(&self) -> Result<Vec<AccountAddress>> {
+        Ok(self.details().await.unused_addresses())
+    }
+
     fn filter_outputs<'a>(
         &self,
         outputs: impl Iterator<Item = &'a OutputData>,
@@ -349,6 +521,17 @@ impl AccountInner {
                     }
                 }
 
+                if let Some(amount_smaller_than) = filter.amount_smaller_than {
+                    if output.output.amount() >= amount_smaller_than {
+                        continue;
+                    }
+                }
+                if let Some(amount_greater_than) = filter.amount_greater_than {
+                    if output.output.amount() <= amount_greater_than {
+                        continue;
+                    }
+                }
+
                 filtered_outputs.push(output.clone());
             }
 
@@ -363,6 +546,18 @@ impl AccountInner {
         self.filter_outputs(self.details().await.outputs.values(), filter)
     }
 
+    /// Returns spent outputs of the account
+    pub async fn spent_outputs(&self, filter: impl Into<Option<FilterOptions>> + Send) -> Result<Vec<OutputData>> {
+        self.filter_outputs(
+            self.details()
+                .await
+                .outputs
+                .values()
+                .filter(|output_data| output_data.is_spent),
+            filter,
+        )
+    }
+
     /// Returns unspent outputs of the account
     pub async fn unspent_outputs(&self, filter: impl Into<Option<FilterOptions>> + Send) -> Result<Vec<OutputData>> {
         self.filter_outputs(self.details().await.unspent_outputs.values(), filter)
@@ -423,6 +618,48 @@ impl AccountInner {
     }
 }
 
+impl AccountDetails {
+    /// Returns the addresses that never had an output, spent or unspent, based on the known outputs and the
+    /// transaction history
+    pub(crate) fn unused_addresses(&self) -> Vec<AccountAddress> {
+        let mut used_addresses = HashSet::new();
+
+        for output_data in self.outputs.values() {
+            used_addresses.insert(output_data.address);
+            insert_unlock_condition_addresses(&output_data.output, &mut used_addresses);
+        }
+
+        for transaction in self.transactions.values().chain(self.incoming_transactions.values()) {
+            let TransactionEssence::Regular(essence) = transaction.payload.essence();
+            for output in essence.outputs() {
+                insert_unlock_condition_addresses(output, &mut used_addresses);
+            }
+        }
+
+        self.public_addresses
+            .iter()
+            .chain(self.internal_addresses.iter())
+            .filter(|address| !address.used && !used_addresses.contains(address.address.inner()))
+            .cloned()
+            .collect()
+    }
+}
+
+// Collect all addresses that can be used in the unlock conditions of an output
+fn insert_unlock_condition_addresses(output: &Output, addresses: &mut HashSet<Address>) {
+    if let Some(unlock_conditions) = output.unlock_conditions() {
+        addresses.extend(unlock_conditions.address().map(|uc| *uc.address()));
+        addresses.extend(unlock_conditions.state_controller_address().map(|uc| *uc.address()));
+        addresses.extend(unlock_conditions.governor_address().map(|uc| *uc.address()));
+        addresses.extend(unlock_conditions.expiration().map(|uc| *uc.return_address()));
+        addresses.extend(
+            unlock_conditions
+                .storage_deposit_return()
+                .map(|uc| *uc.return_address()),
+        );
+    }
+}
+
 pub(crate) fn build_transaction_from_payload_and_inputs(
     tx_id: TransactionId,
     tx_payload: TransactionPayload,
@@ -444,6 +681,7 @@ pub(crate) fn build_transaction_from_payload_and_inputs(
         incoming: true,
         note: None,
         inputs,
+        reattachments: 0,
     })
 }
 
@@ -518,6 +756,7 @@ fn serialize() {
         incoming: false,
         note: None,
         inputs: Vec::new(),
+        reattachments: 0,
     };
 
     let mut incoming_transactions = HashMap::new();
@@ -541,6 +780,8 @@ fn serialize() {
         incoming_transactions,
         inaccessible_incoming_transactions: HashSet::new(),
         native_token_foundries: HashMap::new(),
+        watch_only: false,
+        transaction_notes: HashMap::new(),
     };
 
     serde_json::from_str::<AccountDetails>(&serde_json::to_string(&account).unwrap()).unwrap();
@@ -576,6 +817,8 @@ impl AccountDetails {
             incoming_transactions: HashMap::new(),
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            watch_only: false,
+            transaction_notes: HashMap::new(),
         }
     }
 }
@@ -39,6 +39,9 @@ impl Account {
         }
 
         let account_details = self.details().await;
+        if account_details.watch_only {
+            return Err(crate::wallet::Error::WatchOnlyAccount(account_details.index));
+        }
 
         // get the highest index for the public or internal addresses
         let highest_current_index_plus_one = if options.internal {
@@ -159,6 +162,34 @@ impl Account {
         Ok(generate_addresses)
     }
 
+    /// Returns the first address that never had an output, spent or unspent, and only generates a new address if
+    /// there is none. Calls are serialized with syncing, so a sync can't change the addresses in between and
+    /// concurrent calls don't generate multiple new addresses.
+    pub async fn get_next_unused_address(
+        &self,
+        options: Option<GenerateAddressOptions>,
+    ) -> crate::wallet::Result<AccountAddress> {
+        let options = options.unwrap_or_default();
+        // Hold the sync lock until the address is returned
+        let _last_synced = self.last_synced.lock().await;
+
+        let unused_address = self
+            .details()
+            .await
+            .unused_addresses()
+            .into_iter()
+            .find(|address| address.internal == options.internal);
+
+        match unused_address {
+            Some(address) => Ok(address),
+            None => self
+                .generate_addresses(1, Some(options))
+                .await?
+                .pop()
+                .ok_or(crate::wallet::Error::MissingParameter("address")),
+        }
+    }
+
     /// Generate an internal address and store in the account, internal addresses are used for remainder outputs
     pub(crate) async fn generate_remainder_address(&self) -> crate::wallet::Result<AccountAddress> {
         let result = self
@@ -4,7 +4,10 @@
 use primitive_types::U256;
 
 use crate::{
-    types::block::output::{unlock_condition::UnlockCondition, FoundryId, NativeTokensBuilder, Output, Rent},
+    types::block::{
+        address::{Address, Bech32Address},
+        output::{unlock_condition::UnlockCondition, FoundryId, NativeTokensBuilder, Output, Rent},
+    },
     wallet::account::{
         operations::helpers::time::can_output_be_unlocked_forever_from_now_on,
         types::{AccountBalance, NativeTokensBalance},
@@ -16,14 +19,63 @@ impl Account {
     /// Get the AccountBalance
     pub async fn balance(&self) -> crate::wallet::Result<AccountBalance> {
         log::debug!("[BALANCE] get balance");
+        let local_time = self.client().get_time_checked().await?;
+        self.balance_inner(None, local_time).await
+    }
+
+    /// Get the AccountBalance as if the current time was `unix_time`, so timelock and expiration unlock conditions are
+    /// evaluated at that time. This can be used to know how much will be spendable at a later point, assuming the
+    /// unspent outputs don't change.
+    pub async fn balance_at_time(&self, unix_time: u32) -> crate::wallet::Result<AccountBalance> {
+        log::debug!("[BALANCE] get balance at time {unix_time}");
+        self.balance_inner(None, unix_time).await
+    }
+
+    /// Get the AccountBalance of each provided address, or of each address with unspent outputs if no addresses are
+    /// provided. Outputs are attributed to the account address they're associated with.
+    pub async fn addresses_balance(
+        &self,
+        addresses: Option<Vec<Bech32Address>>,
+    ) -> crate::wallet::Result<Vec<(Bech32Address, AccountBalance)>> {
+        log::debug!("[BALANCE] get addresses balance");
+        let addresses = match addresses {
+            Some(addresses) => addresses,
+            None => self
+                .addresses_with_unspent_outputs()
+                .await?
+                .into_iter()
+                .map(|address| address.address)
+                .collect(),
+        };
+
+        let local_time = self.client().get_time_checked().await?;
+        let mut addresses_balance = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            let balance = self.balance_inner(Some(address.inner()), local_time).await?;
+            addresses_balance.push((address, balance));
+        }
+
+        Ok(addresses_balance)
+    }
+
+    // Calculate the balance of the outputs associated with the provided address, or of all outputs if it's None, with
+    // time related unlock conditions evaluated at `local_time`
+    async fn balance_inner(&self, address: Option<&Address>, local_time: u32) -> crate::wallet::Result<AccountBalance> {
         let mut account_balance = AccountBalance::default();
         #[cfg(feature = "participation")]
         {
-            account_balance.base_coin.voting_power = self.get_voting_power().await?;
+            account_balance.base_coin.voting_power = match address {
+                Some(address) => self
+                    .get_voting_output()
+                    .await?
+                    .filter(|output_data| output_data.address == *address)
+                    .map_or(0, |output_data| output_data.output.amount()),
+                None => self.get_voting_power().await?,
+            };
         }
 
         let unlockable_outputs_with_multiple_unlock_conditions = self
-            .get_unlockable_outputs_with_additional_unlock_conditions(OutputsToClaim::All)
+            .unlockable_outputs_with_additional_unlock_conditions_at(OutputsToClaim::All, local_time)
             .await?;
 
         let account_addresses = self.addresses().await?;
@@ -31,8 +83,6 @@ impl Account {
         let network_id = self.client().get_network_id().await?;
         let rent_structure = self.client().get_rent_structure().await?;
 
-        let local_time = self.client().get_time_checked().await?;
-
         let mut total_rent_amount = 0;
         let mut total_native_tokens = NativeTokensBuilder::new();
 
@@ -43,6 +93,8 @@ impl Account {
             .values()
             // Check if output is from the network we're currently connected to
             .filter(|data| data.network_id == network_id)
+            // Check if output is associated with the requested address
+            .filter(|data| address.map_or(true, |address| data.address == *address))
             .map(|data| (&data.output_id, &data.output));
 
         for (output_id, output) in relevant_unspent_outputs {
@@ -230,8 +282,10 @@ impl Account {
                 continue;
             }
             if let Some(output_data) = account_details.unspent_outputs.get(locked_output) {
-                // Only check outputs that are in this network
-                if output_data.network_id == network_id {
+                // Only check outputs that are in this network and associated with the requested address
+                if output_data.network_id == network_id
+                    && address.map_or(true, |address| output_data.address == *address)
+                {
                     locked_amount += output_data.output.amount();
                     if let Some(native_tokens) = output_data.output.native_tokens() {
                         locked_native_tokens.add_native_tokens(native_tokens.clone())?;
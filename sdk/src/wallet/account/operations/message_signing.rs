@@ -0,0 +1,47 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::slip10::Chain;
+
+use crate::{
+    client::{constants::HD_WALLET_TYPE, secret::SecretManage},
+    types::block::signature::Ed25519Signature,
+    wallet::account::Account,
+};
+
+impl Account {
+    /// Signs an arbitrary message with the ed25519 key of an account address, so that the ownership of the address
+    /// can be proven, e.g. by verifying the signature with [`Ed25519Signature::is_valid()`].
+    /// ```ignore
+    /// let signature = account.sign_message(0, false, b"challenge").await?;
+    /// ```
+    pub async fn sign_message(
+        &self,
+        address_index: u32,
+        internal: bool,
+        message: &[u8],
+    ) -> crate::wallet::Result<Ed25519Signature> {
+        log::debug!("[SIGN MESSAGE] address index {address_index}, internal: {internal}");
+        let account_details = self.details().await;
+        if account_details.watch_only {
+            return Err(crate::wallet::Error::WatchOnlyAccount(account_details.index));
+        }
+
+        let chain = Chain::from_u32_hardened(vec![
+            HD_WALLET_TYPE,
+            account_details.coin_type,
+            account_details.index,
+            internal as u32,
+            address_index,
+        ]);
+        drop(account_details);
+
+        Ok(self
+            .wallet
+            .secret_manager
+            .read()
+            .await
+            .sign_ed25519(message, &chain)
+            .await?)
+    }
+}
@@ -7,6 +7,8 @@ pub(crate) mod address_generation;
 pub(crate) mod balance;
 /// Helper functions
 pub(crate) mod helpers;
+/// The module for signing messages with the key of an address
+pub(crate) mod message_signing;
 /// The module for claiming of outputs with
 /// [`UnlockCondition`](crate::types::block::output::UnlockCondition)s that aren't only
 /// [`AddressUnlockCondition`](crate::types::block::output::unlock_condition::AddressUnlockCondition)
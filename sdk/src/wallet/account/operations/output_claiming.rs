@@ -6,7 +6,7 @@ use std::collections::{HashMap, HashSet};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    client::api::input_selection::minimum_storage_deposit_basic_output,
+    client::api::{input_selection::minimum_storage_deposit_basic_output, PreparedTransactionData},
     types::block::{
         address::Address,
         output::{
@@ -42,10 +42,21 @@ impl Account {
         outputs_to_claim: OutputsToClaim,
     ) -> crate::wallet::Result<Vec<OutputId>> {
         log::debug!("[OUTPUT_CLAIMING] get_unlockable_outputs_with_additional_unlock_conditions");
-        let account_details = self.details().await;
-
         let local_time = self.client().get_time_checked().await?;
 
+        self.unlockable_outputs_with_additional_unlock_conditions_at(outputs_to_claim, local_time)
+            .await
+    }
+
+    // Get the outputs like `get_unlockable_outputs_with_additional_unlock_conditions()`, with time related unlock
+    // conditions evaluated at `local_time`
+    pub(crate) async fn unlockable_outputs_with_additional_unlock_conditions_at(
+        &self,
+        outputs_to_claim: OutputsToClaim,
+        local_time: u32,
+    ) -> crate::wallet::Result<Vec<OutputId>> {
+        let account_details = self.details().await;
+
         // Get outputs for the claim
         let mut output_ids_to_claim: HashSet<OutputId> = HashSet::new();
         for (output_id, output_data) in account_details
@@ -165,13 +176,73 @@ impl Account {
         self.claim_outputs_internal(output_ids_to_claim, basic_outputs).await
     }
 
+    /// Function to prepare the transaction for
+    /// [Account.claim_outputs()](crate::account::Account.claim_outputs). Fails if one of the outputs can't be claimed
+    /// now, for example because it's not in the account, it's used in another transaction or its timelock didn't
+    /// expire yet.
+    pub async fn prepare_claim_outputs(
+        &self,
+        output_ids_to_claim: Vec<OutputId>,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[OUTPUT_CLAIMING] prepare_claim_outputs");
+        let local_time = self.client().get_time_checked().await?;
+        {
+            let account_details = self.details().await;
+            for output_id in &output_ids_to_claim {
+                let claimable = match account_details.unspent_outputs.get(output_id) {
+                    Some(output_data) => {
+                        !account_details.locked_outputs.contains(output_id)
+                            && can_output_be_unlocked_now(
+                                &account_details.addresses_with_unspent_outputs,
+                                // outputs controlled by an alias or nft are currently not considered
+                                &[],
+                                output_data,
+                                local_time,
+                                // Not relevant without alias addresses
+                                None,
+                            )?
+                    }
+                    None => false,
+                };
+                if !claimable {
+                    return Err(crate::wallet::Error::OutputNotClaimable(*output_id));
+                }
+            }
+        }
+
+        let basic_outputs = self.get_basic_outputs_for_additional_inputs().await?;
+        self.prepare_claim_outputs_internal(output_ids_to_claim, basic_outputs)
+            .await
+    }
+
     /// Try to claim basic outputs that have additional unlock conditions to their [AddressUnlockCondition].
     pub(crate) async fn claim_outputs_internal(
         &self,
         output_ids_to_claim: Vec<OutputId>,
-        mut possible_additional_inputs: Vec<OutputData>,
+        possible_additional_inputs: Vec<OutputData>,
     ) -> crate::wallet::Result<Transaction> {
         log::debug!("[OUTPUT_CLAIMING] claim_outputs_internal");
+        let prepared_transaction = self
+            .prepare_claim_outputs_internal(output_ids_to_claim, possible_additional_inputs)
+            .await?;
+        let claim_tx = self.sign_and_submit_transaction(prepared_transaction).await?;
+
+        log::debug!(
+            "[OUTPUT_CLAIMING] Claiming transaction created: block_id: {:?} tx_id: {:?}",
+            claim_tx.block_id,
+            claim_tx.transaction_id
+        );
+        Ok(claim_tx)
+    }
+
+    /// Prepare the transaction to claim basic outputs that have additional unlock conditions to their
+    /// [AddressUnlockCondition].
+    async fn prepare_claim_outputs_internal(
+        &self,
+        output_ids_to_claim: Vec<OutputId>,
+        mut possible_additional_inputs: Vec<OutputData>,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[OUTPUT_CLAIMING] prepare_claim_outputs_internal");
 
         let current_time = self.client().get_time_checked().await?;
         let rent_structure = self.client().get_rent_structure().await?;
@@ -347,29 +418,21 @@ impl Account {
             })?;
         }
 
-        let claim_tx = self
-            .finish_transaction(
-                outputs_to_send,
-                Some(TransactionOptions {
-                    custom_inputs: Some(
-                        outputs_to_claim
-                            .iter()
-                            .map(|o| o.output_id)
-                            // add additional inputs
-                            .chain(additional_inputs)
-                            .collect::<Vec<OutputId>>(),
-                    ),
-                    ..Default::default()
-                }),
-            )
-            .await?;
-
-        log::debug!(
-            "[OUTPUT_CLAIMING] Claiming transaction created: block_id: {:?} tx_id: {:?}",
-            claim_tx.block_id,
-            claim_tx.transaction_id
-        );
-        Ok(claim_tx)
+        self.prepare_transaction(
+            outputs_to_send,
+            Some(TransactionOptions {
+                custom_inputs: Some(
+                    outputs_to_claim
+                        .iter()
+                        .map(|o| o.output_id)
+                        // add additional inputs
+                        .chain(additional_inputs)
+                        .collect::<Vec<OutputId>>(),
+                ),
+                ..Default::default()
+            }),
+        )
+        .await
     }
 }
 
@@ -3,9 +3,16 @@
 
 #[cfg(feature = "ledger_nano")]
 use crate::client::secret::SecretManager;
-use crate::types::block::{
-    input::INPUT_COUNT_MAX,
-    output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokens, NativeTokensBuilder, Output},
+use crate::{
+    client::api::PreparedTransactionData,
+    types::block::{
+        address::{Address, Bech32Address},
+        input::INPUT_COUNT_MAX,
+        output::{
+            unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokens, NativeTokensBuilder, Output,
+            Rent, TokenId,
+        },
+    },
 };
 
 // Constants for the calculation of the amount of inputs we can use with a ledger nano
@@ -67,7 +74,28 @@ impl Account {
         force: bool,
         output_consolidation_threshold: Option<usize>,
     ) -> Result<Transaction> {
-        log::debug!("[OUTPUT_CONSOLIDATION] consolidating outputs if needed");
+        let prepared_transaction = self
+            .prepare_consolidate_outputs(force, output_consolidation_threshold)
+            .await?;
+        let consolidation_tx = self.sign_and_submit_transaction(prepared_transaction).await?;
+
+        log::debug!(
+            "[OUTPUT_CONSOLIDATION] consolidation transaction created: block_id: {:?} tx_id: {:?}",
+            consolidation_tx.block_id,
+            consolidation_tx.transaction_id
+        );
+
+        Ok(consolidation_tx)
+    }
+
+    /// Function to prepare the transaction for
+    /// [Account.consolidate_outputs()](crate::account::Account.consolidate_outputs)
+    pub async fn prepare_consolidate_outputs(
+        &self,
+        force: bool,
+        output_consolidation_threshold: Option<usize>,
+    ) -> Result<PreparedTransactionData> {
+        log::debug!("[OUTPUT_CONSOLIDATION] prepare consolidating outputs if needed");
         #[cfg(feature = "participation")]
         let voting_output = self.get_voting_output().await?;
         let current_time = self.client().get_time_checked().await?;
@@ -165,22 +193,215 @@ impl Account {
                 .finish_output(token_supply)?,
         ];
 
-        let consolidation_tx = self
-            .finish_transaction(
-                consolidation_output,
-                Some(TransactionOptions {
-                    custom_inputs: Some(custom_inputs),
-                    ..Default::default()
-                }),
-            )
-            .await?;
+        self.prepare_transaction(
+            consolidation_output,
+            Some(TransactionOptions {
+                custom_inputs: Some(custom_inputs),
+                ..Default::default()
+            }),
+        )
+        .await
+    }
+
+    /// Consolidate basic outputs holding native tokens, or only the ones holding `token_id` if it's provided, into as
+    /// few outputs as possible, without exceeding the native tokens count limit of an output.
+    pub async fn consolidate_native_tokens(
+        &self,
+        token_id: Option<TokenId>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> Result<Transaction> {
+        let prepared_transaction = self.prepare_consolidate_native_tokens(token_id, options).await?;
+        let consolidation_tx = self.sign_and_submit_transaction(prepared_transaction).await?;
 
         log::debug!(
-            "[OUTPUT_CONSOLIDATION] consolidation transaction created: block_id: {:?} tx_id: {:?}",
+            "[OUTPUT_CONSOLIDATION] native tokens consolidation transaction created: block_id: {:?} tx_id: {:?}",
             consolidation_tx.block_id,
             consolidation_tx.transaction_id
         );
 
         Ok(consolidation_tx)
     }
+
+    /// Function to prepare the transaction for
+    /// [Account.consolidate_native_tokens()](crate::account::Account.consolidate_native_tokens)
+    pub async fn prepare_consolidate_native_tokens(
+        &self,
+        token_id: Option<TokenId>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> Result<PreparedTransactionData> {
+        log::debug!("[OUTPUT_CONSOLIDATION] prepare consolidating native tokens");
+        #[cfg(feature = "participation")]
+        let voting_output = self.get_voting_output().await?;
+        let current_time = self.client().get_time_checked().await?;
+        let token_supply = self.client().get_token_supply().await?;
+        let mut outputs_to_consolidate = Vec::new();
+        let account_details = self.details().await;
+        let account_addresses = &account_details.addresses_with_unspent_outputs[..];
+
+        for (output_id, output_data) in account_details.unspent_outputs() {
+            #[cfg(feature = "participation")]
+            if let Some(ref voting_output) = voting_output {
+                // Keep the voting output, spending it would stop the participation.
+                if output_data.output_id == voting_output.output_id {
+                    continue;
+                }
+            }
+            let holds_native_tokens = output_data.output.native_tokens().map_or(false, |native_tokens| {
+                token_id
+                    .as_ref()
+                    .map_or(!native_tokens.is_empty(), |token_id| native_tokens.contains(token_id))
+            });
+            if holds_native_tokens
+                && !account_details.locked_outputs.contains(output_id)
+                && self.should_consolidate_output(output_data, current_time, account_addresses)?
+            {
+                outputs_to_consolidate.push(output_data.clone());
+            }
+        }
+
+        drop(account_details);
+
+        // Put every input into the first new output that still has room for its native tokens, an input can't hold
+        // more than the maximum itself, so it always fits into a new one.
+        let mut custom_inputs = Vec::new();
+        let mut consolidated_outputs: Vec<(u64, Address, NativeTokensBuilder)> = Vec::new();
+
+        for output_data in outputs_to_consolidate.iter().take(INPUT_COUNT_MAX.into()) {
+            // Only outputs with native tokens were selected above
+            let native_tokens = output_data.output.native_tokens().expect("output has native tokens");
+            let mut fitting_output = None;
+            for (index, (_, _, total_native_tokens)) in consolidated_outputs.iter().enumerate() {
+                if get_new_native_token_count(total_native_tokens, native_tokens)? <= NativeTokens::COUNT_MAX.into() {
+                    fitting_output = Some(index);
+                    break;
+                }
+            }
+            match fitting_output {
+                Some(index) => {
+                    let (amount, _, total_native_tokens) = &mut consolidated_outputs[index];
+                    *amount += output_data.output.amount();
+                    total_native_tokens.add_native_tokens(native_tokens.clone())?;
+                }
+                None => consolidated_outputs.push((
+                    output_data.output.amount(),
+                    output_data.address,
+                    NativeTokensBuilder::from(native_tokens.clone()),
+                )),
+            }
+            custom_inputs.push(output_data.output_id);
+        }
+
+        // Consolidation only makes sense if it reduces the number of outputs
+        if consolidated_outputs.len() == custom_inputs.len() {
+            log::debug!(
+                "[OUTPUT_CONSOLIDATION] no native tokens consolidation possible, available_outputs: {}",
+                custom_inputs.len()
+            );
+            return Err(crate::wallet::Error::NoOutputsToConsolidate {
+                available_outputs: custom_inputs.len(),
+                consolidation_threshold: 2,
+            });
+        }
+
+        let outputs = consolidated_outputs
+            .into_iter()
+            .map(|(amount, address, native_tokens)| {
+                Ok(BasicOutputBuilder::new_with_amount(amount)
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .with_native_tokens(native_tokens.finish()?)
+                    .finish_output(token_supply)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let options: Option<TransactionOptions> = options.into();
+        let mut options = options.unwrap_or_default();
+        options.custom_inputs = Some(custom_inputs);
+
+        self.prepare_transaction(outputs, options).await
+    }
+
+    /// Function to prepare a transaction that sends all available base coins of the account to a single output on the
+    /// destination address. Only basic outputs that can be unlocked now and without storage deposit return are
+    /// swept, so outputs that still need to be claimed or are timelocked are left untouched. Native tokens are
+    /// swept too if `include_native_tokens` is set, otherwise outputs holding native tokens are skipped.
+    pub async fn prepare_sweep(
+        &self,
+        destination: Bech32Address,
+        include_native_tokens: bool,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> Result<PreparedTransactionData> {
+        log::debug!("[OUTPUT_CONSOLIDATION] prepare_sweep");
+        self.client().bech32_hrp_matches(destination.hrp()).await?;
+        #[cfg(feature = "participation")]
+        let voting_output = self.get_voting_output().await?;
+        let current_time = self.client().get_time_checked().await?;
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+
+        let mut total_amount = 0;
+        let mut custom_inputs = Vec::new();
+        let mut total_native_tokens = NativeTokensBuilder::new();
+
+        let account_details = self.details().await;
+        let account_addresses = &account_details.addresses_with_unspent_outputs[..];
+
+        for (output_id, output_data) in account_details.unspent_outputs() {
+            #[cfg(feature = "participation")]
+            if let Some(ref voting_output) = voting_output {
+                // Keep the voting output, spending it would stop the participation.
+                if output_data.output_id == voting_output.output_id {
+                    continue;
+                }
+            }
+            if account_details.locked_outputs.contains(output_id)
+                || !self.should_consolidate_output(output_data, current_time, account_addresses)?
+            {
+                continue;
+            }
+            if let Some(native_tokens) = output_data.output.native_tokens() {
+                if !native_tokens.is_empty() {
+                    if !include_native_tokens {
+                        continue;
+                    }
+                    // Skip output if the max native tokens count would be exceeded
+                    if get_new_native_token_count(&total_native_tokens, native_tokens)? > NativeTokens::COUNT_MAX.into()
+                    {
+                        log::debug!("[OUTPUT_CONSOLIDATION] skipping output to not exceed the max native tokens count");
+                        continue;
+                    }
+                    total_native_tokens.add_native_tokens(native_tokens.clone())?;
+                }
+            }
+            total_amount += output_data.output.amount();
+            custom_inputs.push(*output_id);
+        }
+
+        drop(account_details);
+
+        if custom_inputs.len() > INPUT_COUNT_MAX.into() {
+            return Err(crate::wallet::Error::ConsolidationRequired {
+                output_count: custom_inputs.len(),
+                output_count_max: INPUT_COUNT_MAX,
+            });
+        }
+
+        let sweep_output = BasicOutputBuilder::new_with_amount(total_amount)
+            .add_unlock_condition(AddressUnlockCondition::new(*destination))
+            .with_native_tokens(total_native_tokens.finish()?)
+            .finish_output(token_supply)?;
+
+        let required_storage_deposit = sweep_output.rent_cost(&rent_structure);
+        if total_amount < required_storage_deposit {
+            return Err(crate::wallet::Error::InsufficientFunds {
+                available: total_amount,
+                required: required_storage_deposit,
+            });
+        }
+
+        let options: Option<TransactionOptions> = options.into();
+        let mut options = options.unwrap_or_default();
+        options.custom_inputs = Some(custom_inputs);
+
+        self.prepare_transaction(vec![sweep_output], options).await
+    }
 }
@@ -1,7 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashMap;
+use std::collections::{hash_map::Entry, HashMap};
 
 use crate::{
     client::{node_manager::node::Node, Client},
@@ -70,6 +70,53 @@ impl Account {
         Ok(registered_participation_events)
     }
 
+    /// Queries every node for its voting events and stores the union of them locally, deduplicated by event id. Each
+    /// event keeps all the nodes that reported it, so a node that's missing an event doesn't prevent its registration.
+    ///
+    /// This will NOT store the node urls and auth inside the client options.
+    pub async fn register_participation_events_from_nodes(
+        &self,
+        nodes: &[Node],
+    ) -> crate::wallet::Result<HashMap<ParticipationEventId, ParticipationEventWithNodes>> {
+        let mut registered_participation_events: HashMap<ParticipationEventId, ParticipationEventWithNodes> =
+            HashMap::new();
+        for node in nodes {
+            let client = Client::builder()
+                .with_ignore_node_health()
+                .with_node_auth(node.url.as_str(), node.auth.clone())?
+                .finish()
+                .await?;
+
+            for event_id in client.events(Some(ParticipationEventType::Voting)).await?.event_ids {
+                match registered_participation_events.entry(event_id) {
+                    Entry::Occupied(mut entry) => {
+                        if !entry.get().nodes.contains(node) {
+                            entry.get_mut().nodes.push(node.clone());
+                        }
+                    }
+                    Entry::Vacant(entry) => {
+                        let event_data = client.event(&event_id).await?;
+                        entry.insert(ParticipationEventWithNodes {
+                            id: event_id,
+                            data: event_data,
+                            nodes: vec![node.clone()],
+                        });
+                    }
+                }
+            }
+        }
+
+        let account_index = self.details().await.index;
+        let storage_manager = self.wallet.storage_manager.read().await;
+        for event_with_nodes in registered_participation_events.values() {
+            storage_manager
+                .insert_participation_event(account_index, event_with_nodes.clone())
+                .await?;
+        }
+
+        Ok(registered_participation_events)
+    }
+
     /// Removes a previously registered participation event from local storage.
     pub async fn deregister_participation_event(&self, id: &ParticipationEventId) -> crate::wallet::Result<()> {
         let account_index = self.details().await.index;
@@ -21,7 +21,10 @@ use crate::{
     types::{
         api::plugins::participation::{
             responses::TrackedParticipation,
-            types::{ParticipationEventData, ParticipationEventId, Participations, PARTICIPATION_TAG},
+            types::{
+                Participation, ParticipationEventData, ParticipationEventId, ParticipationEventPayload, Participations,
+                PARTICIPATION_TAG,
+            },
         },
         block::output::{unlock_condition::UnlockCondition, Output, OutputId},
     },
@@ -220,6 +223,75 @@ impl Account {
         Ok(AccountParticipationOverview { participations })
     }
 
+    /// Calculates the staking rewards the account's outputs accrued per event, based on the participation overview. If
+    /// event_ids are provided, only return rewards for them. Voting events and events that aren't registered in the
+    /// account have no rewards, the same applies to staking events if the rewards are below their required minimum.
+    pub async fn get_participation_rewards(
+        &self,
+        event_ids: Option<Vec<ParticipationEventId>>,
+    ) -> Result<HashMap<ParticipationEventId, u64>> {
+        log::debug!("[get_participation_rewards]");
+        let overview = self.get_participation_overview(event_ids).await?;
+        let latest_milestone_index = self.client().get_info().await?.node_info.status.latest_milestone.index;
+
+        let account_index = self.details().await.index;
+        let events = self
+            .wallet
+            .storage_manager
+            .read()
+            .await
+            .get_participation_events(account_index)
+            .await?;
+
+        let mut rewards = HashMap::new();
+        for (event_id, tracked_participations) in overview.participations {
+            let mut event_rewards: u128 = 0;
+            if let Some(event_with_nodes) = events.get(&event_id) {
+                let event_data = &event_with_nodes.data;
+                if let ParticipationEventPayload::StakingEventPayload(staking) = event_data.payload() {
+                    for tracked_participation in tracked_participations.values() {
+                        // Rewards are only given for milestones while the event is running
+                        let start = tracked_participation
+                            .start_milestone_index
+                            .max(*event_data.milestone_index_start());
+                        let end = match tracked_participation.end_milestone_index {
+                            // The participation is still active
+                            0 => latest_milestone_index,
+                            end_milestone_index => end_milestone_index,
+                        }
+                        .min(*event_data.milestone_index_end());
+                        let milestones = end.saturating_sub(start) as u128;
+
+                        event_rewards += (tracked_participation.amount as u128 * *staking.numerator() as u128)
+                            .checked_div(*staking.denominator() as u128)
+                            .unwrap_or_default()
+                            * milestones;
+                    }
+                    if event_rewards < *staking.required_minimum_rewards() as u128 {
+                        event_rewards = 0;
+                    }
+                }
+            }
+            rewards.insert(event_id, u64::try_from(event_rewards).unwrap_or(u64::MAX));
+        }
+
+        Ok(rewards)
+    }
+
+    /// Returns the participations of the voting output, so the events the account is currently voting in together
+    /// with the answers that were cast. Unlike [`Account::get_participation_overview()`], no node is queried.
+    pub async fn get_active_votes(&self) -> Result<Vec<Participation>> {
+        log::debug!("[get_active_votes]");
+        Ok(self
+            .get_voting_output()
+            .await?
+            .and_then(|output_data| {
+                let metadata = output_data.output.features().and_then(|f| f.metadata())?;
+                Participations::from_bytes(&mut metadata.data()).ok()
+            })
+            .map_or_else(Vec::new, |participations| participations.participations))
+    }
+
     /// Returns the voting output ("PARTICIPATION" tag).
     ///
     /// If multiple outputs with this tag exist, the one with the largest amount will be returned.
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    client::api::PreparedTransactionData,
     types::{
         api::plugins::participation::types::{Participations, PARTICIPATION_TAG},
         block::{
@@ -40,6 +41,14 @@ impl Account {
     /// Prioritizes consuming outputs that are designated for voting but don't have any metadata (only possible if user
     /// increases voting power then increases again immediately after).
     pub async fn increase_voting_power(&self, amount: u64) -> Result<Transaction> {
+        let prepared_transaction = self.prepare_increase_voting_power(amount).await?;
+
+        self.sign_and_submit_transaction(prepared_transaction).await
+    }
+
+    /// Function to prepare the transaction for
+    /// [Account.increase_voting_power()](crate::account::Account.increase_voting_power)
+    pub async fn prepare_increase_voting_power(&self, amount: u64) -> Result<PreparedTransactionData> {
         let token_supply = self.client().get_token_supply().await?;
 
         let (new_output, tx_options) = match self.get_voting_output().await? {
@@ -78,7 +87,7 @@ impl Account {
             ),
         };
 
-        self.send(vec![new_output], tx_options).await
+        self.prepare_transaction(vec![new_output], tx_options).await
     }
 
     /// Reduces an account's "voting power" by a given amount.
@@ -1,6 +1,8 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use serde::{Deserialize, Serialize};
+
 use crate::{
     types::{
         api::core::dto::LedgerInclusionStateDto,
@@ -12,10 +14,53 @@ use crate::{
     wallet::account::{types::InclusionState, Account},
 };
 
-const DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL: u64 = 1;
-const DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT: u64 = 40;
+/// The default interval in seconds between the attempts to get a transaction included.
+pub const DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL: u64 = 5;
+/// The default maximum number of attempts to get a transaction included.
+pub const DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT: u64 = 40;
+/// The minimum interval in seconds between the attempts to get a transaction included, shorter intervals are raised
+/// to it so the node isn't spammed.
+pub const MIN_RETRY_UNTIL_INCLUDED_INTERVAL: u64 = 1;
+
+/// The options used to retry transactions until they're included, if none are provided.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetryOptions {
+    /// The interval in seconds between the attempts.
+    pub interval: u64,
+    /// The maximum number of attempts.
+    pub max_attempts: u64,
+}
+
+impl Default for RetryOptions {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL,
+            max_attempts: DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT,
+        }
+    }
+}
 
 impl Account {
+    /// Set the fallback RetryOptions for retrying transactions until they're included.
+    /// If storage is enabled, will persist during restarts.
+    pub async fn set_default_retry_options(&self, options: RetryOptions) -> crate::wallet::Result<()> {
+        #[cfg(feature = "storage")]
+        {
+            let index = *self.details().await.index();
+            let storage_manager = self.wallet.storage_manager.read().await;
+            storage_manager.set_default_retry_options(index, &options).await?;
+        }
+
+        *self.default_retry_options.lock().await = options;
+        Ok(())
+    }
+
+    // Get the default retry options we use when none are provided.
+    pub async fn default_retry_options(&self) -> RetryOptions {
+        *self.default_retry_options.lock().await
+    }
+
     /// Retries (promotes or reattaches) a block for provided block id until it's included (referenced by a
     /// milestone). This function is re-exported from the client library and default interval is as defined there.
     /// Returns the included block at first position and additional reattached blocks
@@ -33,6 +78,9 @@ impl Account {
 
     /// Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
     /// included (referenced by a milestone). Returns the included block id.
+    /// If `interval` or `max_attempts` are `None`, the default retry options of the account are used, which are
+    /// [`DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL`] and [`DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT`] unless changed.
+    /// The interval is at least [`MIN_RETRY_UNTIL_INCLUDED_INTERVAL`] and `max_attempts` can't be zero.
     pub async fn retry_transaction_until_included(
         &self,
         transaction_id: &TransactionId,
@@ -40,6 +88,15 @@ impl Account {
         max_attempts: Option<u64>,
     ) -> crate::wallet::Result<BlockId> {
         log::debug!("[retry_transaction_until_included]");
+        let default_retry_options = self.default_retry_options().await;
+        let interval = interval
+            .unwrap_or(default_retry_options.interval)
+            .max(MIN_RETRY_UNTIL_INCLUDED_INTERVAL);
+        let max_attempts = match max_attempts {
+            Some(0) => return Err(crate::wallet::Error::InvalidMaxAttempts),
+            Some(max_attempts) => max_attempts,
+            None => default_retry_options.max_attempts,
+        };
 
         let transaction = self.details().await.transactions.get(transaction_id).cloned();
 
@@ -72,9 +129,8 @@ impl Account {
 
             // Attachments of the Block to check inclusion state
             let mut block_ids = vec![block_id];
-            for _ in 0..max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT) {
-                let duration =
-                    std::time::Duration::from_secs(interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL));
+            for _ in 0..max_attempts {
+                let duration = std::time::Duration::from_secs(interval);
 
                 #[cfg(target_family = "wasm")]
                 gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
@@ -109,6 +165,7 @@ impl Account {
                                 .finish_block(Some(Payload::Transaction(Box::new(transaction.payload.clone()))))
                                 .await?;
                             block_ids.push(reattached_block.id());
+                            self.count_reattachment(transaction_id).await?;
                         }
                     }
                 }
@@ -124,4 +181,44 @@ impl Account {
             Err(crate::wallet::Error::TransactionNotFound(*transaction_id))
         }
     }
+
+    /// Retries all pending transactions of the account like
+    /// [`retry_transaction_until_included()`](Account::retry_transaction_until_included), one after the other so the
+    /// node isn't flooded with requests. A failing transaction doesn't stop the others, so the result is returned for
+    /// each transaction.
+    pub async fn retry_all_pending_transactions(
+        &self,
+        interval: Option<u64>,
+        max_attempts: Option<u64>,
+    ) -> Vec<(TransactionId, crate::wallet::Result<BlockId>)> {
+        log::debug!("[retry_all_pending_transactions]");
+
+        let transaction_ids = self
+            .details()
+            .await
+            .pending_transactions
+            .iter()
+            .copied()
+            .collect::<Vec<_>>();
+
+        let mut results = Vec::with_capacity(transaction_ids.len());
+        for transaction_id in transaction_ids {
+            let result = self
+                .retry_transaction_until_included(&transaction_id, interval, max_attempts)
+                .await;
+            results.push((transaction_id, result));
+        }
+        results
+    }
+
+    // Increase the reattachment count of a stored transaction
+    async fn count_reattachment(&self, transaction_id: &TransactionId) -> crate::wallet::Result<()> {
+        let mut account_details = self.details_mut().await;
+        if let Some(transaction) = account_details.transactions.get_mut(transaction_id) {
+            transaction.reattachments += 1;
+            #[cfg(feature = "storage")]
+            self.save(Some(&account_details)).await?;
+        }
+        Ok(())
+    }
 }
@@ -11,6 +11,11 @@ use std::collections::HashSet;
 use futures::FutureExt;
 use instant::Instant;
 
+#[cfg(feature = "events")]
+use crate::wallet::{
+    account::constants::SYNC_PROGRESS_EVENT_INTERVAL,
+    events::types::{SyncProgressEvent, WalletEvent},
+};
 use crate::{
     types::block::{address::Address, output::OutputId},
     wallet::account::{
@@ -161,11 +166,20 @@ impl Account {
         let mut addresses_with_outputs = Vec::new();
         // spent outputs or alias/nft/foundries that don't get synced anymore, because of other sync options
         let mut spent_or_not_anymore_synced_outputs = Vec::new();
+        #[cfg(feature = "events")]
+        let (account_index, total_addresses) = (*self.details().await.index(), addresses_with_unspent_outputs.len());
+        #[cfg(feature = "events")]
+        let (mut addresses_scanned, mut outputs_found, mut last_progress_event) = (0, 0, None::<Instant>);
         // We split the addresses into chunks so we don't get timeouts if we have thousands
         for addresses_chunk in &mut addresses_with_unspent_outputs
             .chunks(PARALLEL_REQUESTS_AMOUNT)
             .map(|x: &[AddressWithUnspentOutputs]| x.to_vec())
         {
+            self.check_sync_aborted()?;
+            #[cfg(feature = "events")]
+            {
+                addresses_scanned += addresses_chunk.len();
+            }
             let results;
             #[cfg(target_family = "wasm")]
             {
@@ -199,6 +213,10 @@ impl Account {
 
             for res in results {
                 let (mut address, output_ids): (AddressWithUnspentOutputs, Vec<OutputId>) = res?;
+                #[cfg(feature = "events")]
+                {
+                    outputs_found += output_ids.len();
+                }
                 // only return addresses with outputs
                 if !output_ids.is_empty() {
                     // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
@@ -216,6 +234,23 @@ impl Account {
                     spent_or_not_anymore_synced_outputs.extend(address.output_ids.into_iter());
                 }
             }
+
+            // Report progress after each chunk, but throttled, the last chunk is always reported
+            #[cfg(feature = "events")]
+            if addresses_scanned == total_addresses
+                || last_progress_event.map_or(true, |last| last.elapsed() >= SYNC_PROGRESS_EVENT_INTERVAL)
+            {
+                self.emit(
+                    account_index,
+                    WalletEvent::SyncProgress(SyncProgressEvent {
+                        addresses_scanned,
+                        total_addresses,
+                        outputs_found,
+                    }),
+                )
+                .await;
+                last_progress_event.replace(Instant::now());
+            }
         }
 
         log::debug!(
@@ -25,6 +25,7 @@ impl Account {
             .chunks(PARALLEL_REQUESTS_AMOUNT)
             .map(|x: &[AddressWithUnspentOutputs]| x.to_vec())
         {
+            self.check_sync_aborted()?;
             let mut tasks = Vec::new();
             for address in addresses_chunk {
                 let account = self.clone();
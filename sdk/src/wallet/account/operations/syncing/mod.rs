@@ -7,7 +7,12 @@ pub(crate) mod options;
 pub(crate) mod outputs;
 pub(crate) mod transactions;
 
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use serde::{Deserialize, Serialize};
 
 pub use self::options::SyncOptions;
 use crate::{
@@ -22,6 +27,19 @@ use crate::{
     },
 };
 
+/// When an account was synced the last time and the latest milestone of the node, see
+/// [`Account::sync_status()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncStatus {
+    /// Unix timestamp in milliseconds of the last sync, `None` if the account wasn't synced yet.
+    pub last_synced_at: Option<u64>,
+    /// The latest milestone index of the node when the last sync started.
+    pub synced_up_to_milestone: Option<u32>,
+    /// The latest milestone index of the node, `None` if the node couldn't be reached.
+    pub node_latest_milestone: Option<u32>,
+}
+
 impl Account {
     /// Set the fallback SyncOptions for account syncing.
     /// If storage is enabled, will persist during restarts.
@@ -67,11 +85,20 @@ impl Account {
             return self.balance().await;
         }
 
+        // Everything up to this milestone will be included in the synced data
+        let latest_milestone_index = self
+            .client()
+            .get_info()
+            .await
+            .ok()
+            .map(|info| info.node_info.status.latest_milestone.index);
+
         self.sync_internal(&options).await?;
 
         // Sync transactions after updating account with outputs, so we can use them to check the transaction
         // status
         if options.sync_pending_transactions {
+            self.check_sync_aborted()?;
             let confirmed_tx_with_unknown_output = self.sync_pending_transactions().await?;
             // Sync again if we don't know the output yet, to prevent having no unspent outputs after syncing
             if confirmed_tx_with_unknown_output {
@@ -84,10 +111,31 @@ impl Account {
         // Update last_synced mutex
         let time_now = crate::utils::unix_timestamp_now().as_millis();
         *last_synced = time_now;
+        if latest_milestone_index.is_some() {
+            *self.synced_milestone_index.lock().await = latest_milestone_index;
+        }
         log::debug!("[SYNC] finished syncing in {:.2?}", syc_start_time.elapsed());
         Ok(account_balance)
     }
 
+    /// Returns when the account was synced the last time, together with the latest milestone of the node, so it can be
+    /// seen how far behind the account is. The data of the last sync is also returned if the node can't be reached.
+    pub async fn sync_status(&self) -> SyncStatus {
+        let last_synced = *self.last_synced.lock().await;
+        let node_latest_milestone = self
+            .client()
+            .get_info()
+            .await
+            .ok()
+            .map(|info| info.node_info.status.latest_milestone.index);
+
+        SyncStatus {
+            last_synced_at: (last_synced != 0).then_some(last_synced as u64),
+            synced_up_to_milestone: *self.synced_milestone_index.lock().await,
+            node_latest_milestone,
+        }
+    }
+
     async fn sync_internal(&self, options: &SyncOptions) -> crate::wallet::Result<()> {
         log::debug!("[SYNC] sync_internal");
 
@@ -116,6 +164,8 @@ impl Account {
             spent_or_unsynced_output_metadata_map.insert(output_id, Some(output_metadata_response));
         }
 
+        self.check_sync_aborted()?;
+
         if options.sync_incoming_transactions {
             let transaction_ids = outputs_data
                 .iter()
@@ -140,6 +190,9 @@ impl Account {
             self.request_and_store_foundry_outputs(native_token_foundry_ids).await?;
         }
 
+        // Check a last time before anything is written, so an aborted sync doesn't leave partial results
+        self.check_sync_aborted()?;
+
         // Updates account with balances, output ids, outputs
         self.update_account(
             addresses_with_unspent_outputs,
@@ -150,6 +203,27 @@ impl Account {
         .await
     }
 
+    /// Abort the sync of this account that is in progress at the next address batch, its partial results are
+    /// discarded. Returns once the account isn't syncing anymore.
+    pub async fn abort_sync(&self) -> crate::wallet::Result<()> {
+        log::debug!("[abort_sync]");
+        let _abort_request = SyncAbortRequest::new(&self.sync_abort_requests);
+        // The account holds the last_synced lock while syncing, so once we got it, no sync is running
+        drop(self.last_synced.lock().await);
+        Ok(())
+    }
+
+    // Returns an error if the syncing should be aborted, see `Wallet::abort_sync()` and `Account::abort_sync()`
+    pub(crate) fn check_sync_aborted(&self) -> crate::wallet::Result<()> {
+        if self.wallet.sync_abort_requests.load(Ordering::Relaxed) > 0
+            || self.sync_abort_requests.load(Ordering::Relaxed) > 0
+        {
+            log::debug!("[SYNC] aborted");
+            return Err(crate::wallet::Error::SyncAborted);
+        }
+        Ok(())
+    }
+
     // First request all outputs directly related to the ed25519 addresses, then for each nft and alias output we got,
     // request all outputs that are related to their alias/nft addresses in a loop until no new alias or nft outputs is
     // found
@@ -246,3 +320,20 @@ impl Account {
         ))
     }
 }
+
+// A pending abort request, it's removed again when dropped, so it can't stay around if `abort_sync()` doesn't run to
+// completion
+pub(crate) struct SyncAbortRequest<'a>(&'a AtomicUsize);
+
+impl<'a> SyncAbortRequest<'a> {
+    pub(crate) fn new(sync_abort_requests: &'a AtomicUsize) -> Self {
+        sync_abort_requests.fetch_add(1, Ordering::Relaxed);
+        Self(sync_abort_requests)
+    }
+}
+
+impl Drop for SyncAbortRequest<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
@@ -181,7 +181,9 @@ impl Account {
             let mut account_details = self.details_mut().await;
             for res in results {
                 match res? {
-                    (transaction_id, Some(transaction)) => {
+                    (transaction_id, Some(mut transaction)) => {
+                        // Keep local notes for transactions that are requested again, e.g. after a restore
+                        transaction.note = account_details.transaction_notes.get(&transaction_id).cloned();
                         account_details
                             .incoming_transactions
                             .insert(transaction_id, transaction);
@@ -203,6 +203,7 @@ impl Account {
             log::debug!("[SYNC] reattach transaction");
             let reattached_block = self.submit_transaction_payload(transaction.payload.clone()).await?;
             transaction.block_id.replace(reattached_block);
+            transaction.reattachments += 1;
             updated_transactions.push(transaction);
         }
 
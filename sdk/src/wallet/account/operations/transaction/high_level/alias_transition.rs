@@ -0,0 +1,113 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::api::PreparedTransactionData,
+    types::block::{
+        address::Bech32Address,
+        output::{
+            feature::SenderFeature,
+            unlock_condition::{GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition},
+            AliasId, AliasOutputBuilder, Output, Rent, StateMetadataLength,
+        },
+    },
+    wallet::{
+        account::{Account, TransactionOptions},
+        Error,
+    },
+};
+
+impl Account {
+    /// Function to prepare a governance transition of an alias output, which changes its state controller and/or
+    /// governor. The state index and everything else stays the same, so the alias input has to be unlocked by the
+    /// current governor.
+    pub async fn prepare_governance_transition(
+        &self,
+        alias_id: AliasId,
+        new_state_controller: Option<Bech32Address>,
+        new_governor: Option<Bech32Address>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_governance_transition");
+        if new_state_controller.is_none() && new_governor.is_none() {
+            return Err(Error::MissingParameter("new_state_controller or new_governor"));
+        }
+        let token_supply = self.client().get_token_supply().await?;
+
+        let (_, alias_output_data) = self
+            .get_alias_output(Some(alias_id))
+            .await
+            .ok_or(Error::AliasNotFoundInUnspentOutputs(alias_id))?;
+        let alias_output = match &alias_output_data.output {
+            Output::Alias(alias_output) => alias_output,
+            _ => unreachable!("get_alias_output only returns alias outputs"),
+        };
+
+        // Don't update the state index, otherwise it would be a state transition
+        let mut alias_output_builder = AliasOutputBuilder::from(alias_output).with_alias_id(alias_id);
+        if let Some(state_controller) = new_state_controller {
+            self.client().bech32_hrp_matches(state_controller.hrp()).await?;
+            alias_output_builder = alias_output_builder
+                .replace_unlock_condition(StateControllerAddressUnlockCondition::new(*state_controller.inner()));
+        }
+        if let Some(governor) = new_governor {
+            self.client().bech32_hrp_matches(governor.hrp()).await?;
+            alias_output_builder =
+                alias_output_builder.replace_unlock_condition(GovernorAddressUnlockCondition::new(*governor.inner()));
+        }
+
+        let outputs = vec![alias_output_builder.finish_output(token_supply)?];
+
+        self.prepare_transaction(outputs, options).await
+    }
+
+    /// Function to prepare a state transition of an alias output, which increments its state index and can update
+    /// its state metadata and sender feature. The alias input has to be unlocked by the current state controller. If
+    /// the new state metadata increases the required storage deposit, the amount of the alias output is raised to it.
+    pub async fn prepare_alias_state_transition(
+        &self,
+        alias_id: AliasId,
+        new_state_metadata: Option<Vec<u8>>,
+        new_sender: Option<Bech32Address>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_alias_state_transition");
+        if let Some(state_metadata) = &new_state_metadata {
+            StateMetadataLength::try_from(state_metadata.len())
+                .map_err(crate::types::block::Error::InvalidStateMetadataLength)?;
+        }
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+
+        let (_, alias_output_data) = self
+            .get_alias_output(Some(alias_id))
+            .await
+            .ok_or(Error::AliasNotFoundInUnspentOutputs(alias_id))?;
+        let alias_output = match &alias_output_data.output {
+            Output::Alias(alias_output) => alias_output,
+            _ => unreachable!("get_alias_output only returns alias outputs"),
+        };
+
+        // The incremented state index makes it a state transition
+        let mut alias_output_builder = AliasOutputBuilder::from(alias_output)
+            .with_alias_id(alias_id)
+            .with_state_index(alias_output.state_index() + 1);
+        if let Some(state_metadata) = new_state_metadata {
+            alias_output_builder = alias_output_builder.with_state_metadata(state_metadata);
+        }
+        if let Some(sender) = new_sender {
+            self.client().bech32_hrp_matches(sender.hrp()).await?;
+            alias_output_builder = alias_output_builder.replace_feature(SenderFeature::new(*sender.inner()));
+        }
+
+        let mut new_alias_output = alias_output_builder.clone().finish_output(token_supply)?;
+        let required_storage_deposit = new_alias_output.rent_cost(&rent_structure);
+        if new_alias_output.amount() < required_storage_deposit {
+            new_alias_output = alias_output_builder
+                .with_amount(required_storage_deposit)
+                .finish_output(token_supply)?;
+        }
+
+        self.prepare_transaction(vec![new_alias_output], options).await
+    }
+}
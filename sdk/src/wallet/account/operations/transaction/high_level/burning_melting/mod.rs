@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    client::api::input_selection::Burn,
+    client::api::{input_selection::Burn, PreparedTransactionData},
     wallet::{
         account::{types::Transaction, TransactionOptions},
         Account,
@@ -22,11 +22,23 @@ impl Account {
         burn: impl Into<Burn> + Send,
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<Transaction> {
+        let prepared_transaction = self.prepare_burn(burn, options).await?;
+        self.sign_and_submit_transaction(prepared_transaction).await
+    }
+
+    /// Function to prepare the transaction for
+    /// [Account.burn()](crate::account::Account.burn)
+    pub async fn prepare_burn(
+        &self,
+        burn: impl Into<Burn> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_burn");
         let mut options: TransactionOptions = options.into().unwrap_or_default();
         options.burn = Some(burn.into());
 
         // The empty list of outputs is used. Outputs will be generated by
         // the input selection algorithm based on the content of the [`Burn`] object.
-        self.send(vec![], Some(options)).await
+        self.prepare_transaction(vec![], Some(options)).await
     }
 }
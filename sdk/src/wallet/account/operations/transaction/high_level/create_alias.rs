@@ -99,7 +99,11 @@ impl Account {
         self.sign_and_submit_transaction(prepared_transaction).await
     }
 
-    pub(crate) async fn prepare_create_alias_output(
+    /// Function to prepare the transaction for
+    /// [Account.create_alias_output()](crate::account::Account.create_alias_output). The alias output has a null
+    /// alias id, which is replaced by the id derived from its output id once the transaction is signed, and holds the
+    /// minimum required storage deposit.
+    pub async fn prepare_create_alias_output(
         &self,
         params: Option<CreateAliasParams>,
         options: impl Into<Option<TransactionOptions>> + Send,
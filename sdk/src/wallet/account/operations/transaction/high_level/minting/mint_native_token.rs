@@ -5,6 +5,7 @@ use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    client::api::PreparedTransactionData,
     types::block::{
         address::AliasAddress,
         output::{
@@ -93,8 +94,8 @@ impl From<&MintTokenTransaction> for MintTokenTransactionDto {
 
 impl Account {
     /// Function to create a new foundry output with minted native tokens.
-    /// Calls [Account.send()](crate::account::Account.send) internally, the options can define the
-    /// RemainderValueStrategy or custom inputs.
+    /// Calls [Account.prepare_transaction()](crate::account::Account.prepare_transaction) internally, the options can
+    /// define the RemainderValueStrategy or custom inputs.
     /// Address needs to be Bech32 encoded
     /// ```ignore
     /// let params = MintNativeTokenParams {
@@ -116,6 +117,32 @@ impl Account {
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<MintTokenTransaction> {
         log::debug!("[TRANSACTION] mint_native_token");
+        let (token_id, prepared_transaction) = self.prepare_mint_native_token_with_id(params, options).await?;
+        self.sign_and_submit_transaction(prepared_transaction)
+            .await
+            .map(|transaction| MintTokenTransaction { token_id, transaction })
+    }
+
+    /// Function to prepare the transaction for
+    /// [Account.mint_native_token()](crate::account::Account.mint_native_token). The prepared outputs contain the new
+    /// foundry output, the id of the minted token can be derived from it with
+    /// [`FoundryOutput::token_id()`](crate::types::block::output::FoundryOutput::token_id).
+    pub async fn prepare_mint_native_token(
+        &self,
+        params: MintNativeTokenParams,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_mint_native_token");
+        self.prepare_mint_native_token_with_id(params, options)
+            .await
+            .map(|(_, prepared_transaction)| prepared_transaction)
+    }
+
+    async fn prepare_mint_native_token_with_id(
+        &self,
+        params: MintNativeTokenParams,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<(TokenId, PreparedTransactionData)> {
         let rent_structure = self.client().get_rent_structure().await?;
         let token_supply = self.client().get_token_supply().await?;
 
@@ -160,9 +187,9 @@ impl Account {
                     foundry_builder.finish_output(token_supply)?
                 }, // Native Tokens will be added automatically in the remainder output in try_select_inputs()
             ];
-            self.send(outputs, options)
+            self.prepare_transaction(outputs, options)
                 .await
-                .map(|transaction| MintTokenTransaction { token_id, transaction })
+                .map(|prepared_transaction| (token_id, prepared_transaction))
         } else {
             unreachable!("We checked if it's an alias output before")
         }
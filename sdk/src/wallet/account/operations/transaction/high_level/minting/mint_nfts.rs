@@ -6,12 +6,13 @@ use serde::{Deserialize, Serialize};
 use crate::{
     client::api::PreparedTransactionData,
     types::block::{
-        address::Address,
+        address::{Address, NftAddress},
         output::{
             feature::{IssuerFeature, MetadataFeature, SenderFeature, TagFeature},
             unlock_condition::AddressUnlockCondition,
-            NftId, NftOutputBuilder,
+            NftId, NftOutputBuilder, Output, OutputId, OUTPUT_COUNT_MAX,
         },
+        payload::transaction::TransactionEssence,
         Error as BlockError,
     },
     wallet::{
@@ -119,9 +120,62 @@ impl Account {
         self.sign_and_submit_transaction(prepared_transaction).await
     }
 
+    /// Function to mint an NFT collection: first the issuer NFT of the collection is minted, then, once it's
+    /// included, all items are minted in a second transaction with their issuer feature set to the issuer NFT.
+    /// The issuer NFT needs to be unlocked to mint the items, so this can't be done in a single transaction.
+    /// Returns the transaction that minted the items. Fails before sending anything if the items don't fit into a
+    /// single transaction.
+    pub async fn mint_nft_collection(
+        &self,
+        issuer_nft_params: MintNftParams,
+        items: Vec<MintNftParams>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        log::debug!("[TRANSACTION] mint_nft_collection");
+        // The issuer NFT and a remainder also need to fit into the transaction of the items
+        let item_count_max = OUTPUT_COUNT_MAX as usize - 2;
+        if items.len() > item_count_max {
+            return Err(WalletError::NftCollectionTooLarge {
+                item_count: items.len(),
+                item_count_max,
+            });
+        }
+        let options: Option<TransactionOptions> = options.into();
+
+        let issuer_transaction = self.mint_nfts(vec![issuer_nft_params], options.clone()).await?;
+        self.retry_transaction_until_included(&issuer_transaction.transaction_id, None, None)
+            .await?;
+        // Sync so the issuer NFT is available as input
+        self.sync(None).await?;
+
+        let TransactionEssence::Regular(essence) = issuer_transaction.payload.essence();
+        let issuer_nft_output_index = essence
+            .outputs()
+            .iter()
+            .position(|output| matches!(output, Output::Nft(nft_output) if nft_output.nft_id().is_null()))
+            .ok_or_else(|| WalletError::MintingFailed("issuer NFT output not found".to_string()))?;
+        let issuer_nft_id = NftId::from(&OutputId::new(
+            issuer_transaction.transaction_id,
+            issuer_nft_output_index as u16,
+        )?);
+        let issuer = Address::Nft(NftAddress::new(issuer_nft_id)).to_bech32(self.client().get_bech32_hrp().await?);
+
+        let items = items
+            .into_iter()
+            .map(|item| MintNftParams {
+                issuer: Some(issuer.clone()),
+                ..item
+            })
+            .collect();
+
+        self.mint_nfts(items, options).await
+    }
+
     /// Function to prepare the transaction for
-    /// [Account.mint_nfts()](crate::account::Account.mint_nfts)
-    async fn prepare_mint_nfts(
+    /// [Account.mint_nfts()](crate::account::Account.mint_nfts). Each NFT is minted in an output with a null NFT id,
+    /// its id is derived from the output id, so from the id of the transaction and the index of the output, once
+    /// the transaction is signed.
+    pub async fn prepare_mint_nfts(
         &self,
         params: Vec<MintNftParams>,
         options: impl Into<Option<TransactionOptions>> + Send,
@@ -1,6 +1,7 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub(crate) mod alias_transition;
 pub(crate) mod burning_melting;
 pub(crate) mod create_alias;
 pub(crate) mod minimum_storage_deposit;
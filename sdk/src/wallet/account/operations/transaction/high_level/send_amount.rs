@@ -1,17 +1,19 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     client::api::PreparedTransactionData,
     types::block::{
-        address::Address,
+        address::{Address, Bech32Address},
         output::{
+            feature::{MetadataFeature, TagFeature},
             unlock_condition::{
                 AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
             },
-            BasicOutputBuilder,
+            BasicOutputBuilder, NativeToken, Output, Rent, RentStructure, TokenId, OUTPUT_COUNT_MAX,
         },
     },
     wallet::{
@@ -65,6 +67,24 @@ impl SendAmountParams {
     }
 }
 
+/// Parameters for a recipient of `prepare_send_batch()`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipientParams {
+    /// Bech32 encoded address
+    pub address: Bech32Address,
+    /// Amount, which has to cover the storage deposit of the output
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// Expiration in seconds, after which the output will be available for the return address, if not spent by the
+    /// receiver before. No expiration is added if it's not provided.
+    pub expiration: Option<u32>,
+    /// Bech32 encoded return address of the expiration. Default will use the first address of the account
+    pub return_address: Option<Bech32Address>,
+    /// Native tokens
+    pub native_tokens: Option<Vec<(TokenId, U256)>>,
+}
+
 impl Account {
     /// Function to create basic outputs with which we then will call
     /// [Account.send()](crate::account::Account.send), the options can define the
@@ -119,16 +139,7 @@ impl Account {
             let (bech32_hrp, address) = Address::try_from_bech32_with_hrp(address)?;
             self.client().bech32_hrp_matches(&bech32_hrp).await?;
             let return_address = return_address
-                .map(|address| {
-                    let (hrp, address) = Address::try_from_bech32_with_hrp(address)?;
-                    if bech32_hrp != hrp {
-                        Err(crate::client::Error::InvalidBech32Hrp {
-                            provided: hrp,
-                            expected: bech32_hrp,
-                        })?;
-                    }
-                    Ok::<_, Error>(address)
-                })
+                .map(|address| parse_return_address(address, bech32_hrp))
                 .transpose()?
                 .unwrap_or(default_return_address.address.inner);
 
@@ -186,4 +197,259 @@ impl Account {
 
         self.prepare_transaction(outputs, options).await
     }
+
+    /// Function to prepare a transaction that sends outputs with a [`StorageDepositReturnUnlockCondition`] and an
+    /// [`ExpirationUnlockCondition`], independently of the amount. The storage deposit required with these unlock
+    /// conditions is always added on top of the provided amount, so any amount can be sent. The receiver gets the
+    /// provided amount, the storage deposit is sent back to the return address when claiming and the whole output is
+    /// available for the return address again after the expiration.
+    pub async fn prepare_send_with_expiration(
+        &self,
+        params: Vec<SendAmountParams>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_send_with_expiration");
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+
+        let account_addresses = self.addresses().await?;
+        let default_return_address = account_addresses.first().ok_or(Error::FailedToGetRemainder)?;
+
+        let local_time = self.client().get_time_checked().await?;
+
+        let mut outputs = Vec::new();
+        for SendAmountParams {
+            address,
+            amount,
+            return_address,
+            expiration,
+        } in params
+        {
+            let (bech32_hrp, address) = Address::try_from_bech32_with_hrp(address)?;
+            self.client().bech32_hrp_matches(&bech32_hrp).await?;
+            let return_address = return_address
+                .map(|address| parse_return_address(address, bech32_hrp))
+                .transpose()?
+                .unwrap_or(default_return_address.address.inner);
+
+            let expiration_time = local_time + expiration.unwrap_or(DEFAULT_EXPIRATION_TIME);
+
+            let storage_deposit_amount = minimum_storage_deposit_basic_native_tokens(
+                &rent_structure,
+                &address,
+                &return_address,
+                None,
+                token_supply,
+            )?;
+
+            // Add the storage deposit, so the receiver can get the full amount. It's calculated with all unlock
+            // conditions of the output, so the output always covers its required storage deposit
+            let output_amount = amount
+                .checked_add(storage_deposit_amount)
+                .ok_or(crate::types::block::Error::InvalidOutputAmount(amount))?;
+            let output = BasicOutputBuilder::new_with_amount(output_amount)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .add_unlock_condition(StorageDepositReturnUnlockCondition::new(
+                    return_address,
+                    storage_deposit_amount,
+                    token_supply,
+                )?)
+                .add_unlock_condition(ExpirationUnlockCondition::new(return_address, expiration_time)?)
+                .finish_output(token_supply)?;
+
+            outputs.push(output);
+        }
+
+        self.prepare_transaction(outputs, options).await
+    }
+
+    /// Function to prepare a transaction that sends a basic output with an optional [`MetadataFeature`] and
+    /// [`TagFeature`]. The features increase the required storage deposit, so the amount has to cover it, it isn't
+    /// topped up.
+    pub async fn prepare_send_with_metadata(
+        &self,
+        address: Bech32Address,
+        amount: u64,
+        metadata: Option<Vec<u8>>,
+        tag: Option<Vec<u8>>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_send_with_metadata");
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+
+        self.client().bech32_hrp_matches(address.hrp()).await?;
+
+        let mut output_builder =
+            BasicOutputBuilder::new_with_amount(amount).add_unlock_condition(AddressUnlockCondition::new(*address));
+        if let Some(metadata) = metadata {
+            output_builder = output_builder.add_feature(MetadataFeature::new(metadata)?);
+        }
+        if let Some(tag) = tag {
+            output_builder = output_builder.add_feature(TagFeature::new(tag)?);
+        }
+        let output = output_builder.finish_output(token_supply)?;
+
+        let required_storage_deposit = output.rent_cost(&rent_structure);
+        if amount < required_storage_deposit {
+            return Err(crate::types::block::Error::InsufficientStorageDepositAmount {
+                amount,
+                required: required_storage_deposit,
+            }
+            .into());
+        }
+
+        self.prepare_transaction(vec![output], options).await
+    }
+
+    /// Function to prepare a transaction that sends `count` basic outputs of `amount_each` to the same address, for
+    /// example to distribute funds in many small outputs. Every output has to cover its storage deposit and one output
+    /// of the transaction is kept for a possible remainder.
+    pub async fn prepare_split_output(
+        &self,
+        address: Bech32Address,
+        amount_each: u64,
+        count: u32,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_split_output");
+        // Leave space for a remainder output
+        let output_count_max = OUTPUT_COUNT_MAX - 1;
+        if count == 0 || count > output_count_max as u32 {
+            return Err(Error::InvalidOutputCount {
+                output_count: count,
+                output_count_max,
+            });
+        }
+
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+
+        self.client().bech32_hrp_matches(address.hrp()).await?;
+
+        let output = BasicOutputBuilder::new_with_amount(amount_each)
+            .add_unlock_condition(AddressUnlockCondition::new(*address))
+            .finish_output(token_supply)?;
+
+        let required_storage_deposit = output.rent_cost(&rent_structure);
+        if amount_each < required_storage_deposit {
+            return Err(crate::types::block::Error::InsufficientStorageDepositAmount {
+                amount: amount_each,
+                required: required_storage_deposit,
+            }
+            .into());
+        }
+
+        self.prepare_transaction(vec![output; count as usize], options).await
+    }
+
+    /// Function to prepare a transaction that sends a basic output to every recipient, each with its own amount,
+    /// native tokens and optional expiration. The amount of every output has to cover its storage deposit, it isn't
+    /// topped up. If a recipient is invalid, the error contains its index.
+    pub async fn prepare_send_batch(
+        &self,
+        recipients: Vec<RecipientParams>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_send_batch");
+        // Leave space for a remainder output
+        let output_count_max = OUTPUT_COUNT_MAX - 1;
+        if recipients.is_empty() || recipients.len() > output_count_max as usize {
+            return Err(Error::InvalidOutputCount {
+                output_count: recipients.len() as u32,
+                output_count_max,
+            });
+        }
+
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+
+        let account_addresses = self.addresses().await?;
+        let default_return_address = account_addresses.first().ok_or(Error::FailedToGetRemainder)?;
+
+        let local_time = self.client().get_time_checked().await?;
+
+        let outputs = recipients
+            .into_iter()
+            .enumerate()
+            .map(|(index, recipient)| {
+                recipient_output(
+                    recipient,
+                    &bech32_hrp,
+                    default_return_address.address.inner,
+                    local_time,
+                    &rent_structure,
+                    token_supply,
+                )
+                .map_err(|error| Error::InvalidRecipient {
+                    index,
+                    error: Box::new(error),
+                })
+            })
+            .collect::<crate::wallet::Result<Vec<_>>>()?;
+
+        self.prepare_transaction(outputs, options).await
+    }
+}
+
+// Build the output for a recipient of `prepare_send_batch()` and check that its amount covers the storage deposit
+fn recipient_output(
+    recipient: RecipientParams,
+    bech32_hrp: &str,
+    default_return_address: Address,
+    local_time: u32,
+    rent_structure: &RentStructure,
+    token_supply: u64,
+) -> crate::wallet::Result<Output> {
+    for address in std::iter::once(&recipient.address).chain(&recipient.return_address) {
+        if address.hrp() != bech32_hrp {
+            Err(crate::client::Error::InvalidBech32Hrp {
+                provided: address.hrp().to_string(),
+                expected: bech32_hrp.to_string(),
+            })?;
+        }
+    }
+
+    let mut output_builder = BasicOutputBuilder::new_with_amount(recipient.amount)
+        .add_unlock_condition(AddressUnlockCondition::new(*recipient.address));
+    if let Some(native_tokens) = recipient.native_tokens {
+        output_builder = output_builder.with_native_tokens(
+            native_tokens
+                .into_iter()
+                .map(|(token_id, amount)| NativeToken::new(token_id, amount))
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+    }
+    if let Some(expiration) = recipient.expiration {
+        let return_address = recipient
+            .return_address
+            .map_or(default_return_address, |address| *address);
+        output_builder = output_builder
+            .add_unlock_condition(ExpirationUnlockCondition::new(return_address, local_time + expiration)?);
+    }
+    let output = output_builder.finish_output(token_supply)?;
+
+    let required_storage_deposit = output.rent_cost(rent_structure);
+    if recipient.amount < required_storage_deposit {
+        return Err(crate::types::block::Error::InsufficientStorageDepositAmount {
+            amount: recipient.amount,
+            required: required_storage_deposit,
+        }
+        .into());
+    }
+
+    Ok(output)
+}
+
+// Parse a bech32 return address and check that its HRP matches the one of the receiving address
+fn parse_return_address(return_address: String, bech32_hrp: String) -> crate::wallet::Result<Address> {
+    let (hrp, address) = Address::try_from_bech32_with_hrp(return_address)?;
+    if bech32_hrp != hrp {
+        Err(crate::client::Error::InvalidBech32Hrp {
+            provided: hrp,
+            expected: bech32_hrp,
+        })?;
+    }
+    Ok(address)
 }
@@ -20,6 +20,7 @@ use crate::{
     types::{
         api::core::response::OutputWithMetadataResponse,
         block::{
+            address::Address,
             output::{
                 dto::{OutputDto, OutputMetadataDto},
                 Output,
@@ -29,7 +30,7 @@ use crate::{
         },
     },
     wallet::account::{
-        types::{InclusionState, Transaction},
+        types::{address::AccountAddress, InclusionState, Transaction},
         Account,
     },
 };
@@ -177,6 +178,7 @@ impl Account {
             incoming: false,
             note: None,
             inputs,
+            reattachments: 0,
         };
 
         let mut account_details = self.details_mut().await;
@@ -192,8 +194,9 @@ impl Account {
         Ok(transaction)
     }
 
-    // unlock outputs
-    async fn unlock_inputs(&self, inputs: &[InputSigningData]) -> crate::wallet::Result<()> {
+    /// Unlock the inputs of a transaction, so they are available for a new transaction. Needs to be called for prepared
+    /// transactions that will not be signed and submitted.
+    pub async fn unlock_inputs(&self, inputs: &[InputSigningData]) -> crate::wallet::Result<()> {
         let mut account_details = self.details_mut().await;
         for input_signing_data in inputs {
             let output_id = input_signing_data.output_id();
@@ -205,4 +208,27 @@ impl Account {
         }
         Ok(())
     }
+
+    /// Check that a custom remainder address belongs to the account, unless `allow_external_remainder` is set.
+    pub(crate) async fn validate_custom_remainder_address(
+        &self,
+        address: &AccountAddress,
+        options: &TransactionOptions,
+    ) -> crate::wallet::Result<Address> {
+        let remainder_address = address.address().inner;
+
+        if !options.allow_external_remainder
+            && !self
+                .addresses()
+                .await?
+                .iter()
+                .any(|account_address| account_address.address().inner == remainder_address)
+        {
+            return Err(crate::wallet::Error::ExternalRemainderAddress(
+                address.address().to_string(),
+            ));
+        }
+
+        Ok(remainder_address)
+    }
 }
@@ -31,6 +31,9 @@ pub struct TransactionOptions {
     pub note: Option<String>,
     #[serde(default)]
     pub allow_micro_amount: bool,
+    /// Allow a `RemainderValueStrategy::CustomAddress` that doesn't belong to the account.
+    #[serde(default)]
+    pub allow_external_remainder: bool,
 }
 
 impl TransactionOptions {
@@ -48,6 +51,7 @@ impl TransactionOptions {
             burn: value.burn.as_ref().map(Burn::try_from).transpose()?,
             note: value.note.clone(),
             allow_micro_amount: value.allow_micro_amount,
+            allow_external_remainder: value.allow_external_remainder,
         })
     }
 }
@@ -70,6 +74,8 @@ pub struct TransactionOptionsDto {
     pub note: Option<String>,
     #[serde(default)]
     pub allow_micro_amount: bool,
+    #[serde(default)]
+    pub allow_external_remainder: bool,
 }
 
 #[allow(clippy::enum_variant_names)]
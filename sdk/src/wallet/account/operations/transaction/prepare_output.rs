@@ -374,7 +374,9 @@ impl Account {
                         let remainder_address = self.generate_remainder_address().await?;
                         Some(remainder_address.address().inner)
                     }
-                    RemainderValueStrategy::CustomAddress(address) => Some(address.address().inner),
+                    RemainderValueStrategy::CustomAddress(address) => {
+                        Some(self.validate_custom_remainder_address(address, options).await?)
+                    }
                 }
             }
             None => None,
@@ -90,7 +90,9 @@ impl Account {
                         }
                         Some(remainder_address.address().inner)
                     }
-                    RemainderValueStrategy::CustomAddress(address) => Some(address.address().inner),
+                    RemainderValueStrategy::CustomAddress(address) => {
+                        Some(self.validate_custom_remainder_address(address, options).await?)
+                    }
                 }
             }
             None => None,
@@ -25,6 +25,16 @@ impl Account {
     ) -> crate::wallet::Result<SignedTransactionData> {
         log::debug!("[TRANSACTION] sign_transaction_essence");
         log::debug!("[TRANSACTION] prepared_transaction_data {prepared_transaction_data:?}");
+        {
+            let account_details = self.details().await;
+            if account_details.watch_only {
+                let account_index = account_details.index;
+                drop(account_details);
+                // unlock outputs so they are available for a new transaction
+                self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+                return Err(crate::wallet::Error::WatchOnlyAccount(account_index));
+            }
+        }
         #[cfg(feature = "events")]
         self.emit(
             self.details().await.index,
@@ -54,6 +54,8 @@ impl std::ops::AddAssign for AccountBalance {
         self.nfts.extend(rhs.nfts.into_iter());
         self.aliases.extend(rhs.aliases.into_iter());
         self.foundries.extend(rhs.foundries.into_iter());
+        self.potentially_locked_outputs
+            .extend(rhs.potentially_locked_outputs.into_iter());
     }
 }
 
@@ -218,7 +220,7 @@ impl AccountBalance {
     pub fn rand_mock() -> Self {
         use rand::Rng;
 
-        use crate::types::block::rand::bytes::rand_bytes_array;
+        use crate::types::block::rand::{bytes::rand_bytes_array, output::rand_output_id};
 
         let token_supply = crate::types::block::protocol::protocol_parameters().token_supply();
         let total = rand::thread_rng().gen_range(128..token_supply / 1000000);
@@ -262,6 +264,9 @@ impl AccountBalance {
         let foundries = std::iter::repeat_with(|| FoundryId::from(rand_bytes_array()))
             .take(rand::thread_rng().gen_range(0..10))
             .collect::<Vec<_>>();
+        let potentially_locked_outputs = std::iter::repeat_with(|| (rand_output_id(), rand::random()))
+            .take(rand::thread_rng().gen_range(0..10))
+            .collect::<HashMap<_, _>>();
 
         Self {
             base_coin: BaseCoinBalance {
@@ -280,7 +285,7 @@ impl AccountBalance {
             aliases,
             foundries,
             nfts,
-            ..Default::default()
+            potentially_locked_outputs,
         }
     }
 }
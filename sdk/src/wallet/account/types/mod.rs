@@ -155,6 +155,9 @@ pub struct Transaction {
     // serde(default) is needed so it doesn't break with old dbs
     #[serde(default)]
     pub inputs: Vec<OutputWithMetadataResponse>,
+    /// Number of times the transaction got reattached by the wallet
+    #[serde(default)]
+    pub reattachments: u32,
 }
 
 /// Dto for a transaction with metadata
@@ -176,6 +179,8 @@ pub struct TransactionDto {
     pub incoming: bool,
     pub note: Option<String>,
     pub inputs: Vec<OutputWithMetadataResponse>,
+    /// Number of times the transaction got reattached by the wallet
+    pub reattachments: u32,
 }
 
 impl From<&Transaction> for TransactionDto {
@@ -190,6 +195,7 @@ impl From<&Transaction> for TransactionDto {
             incoming: value.incoming,
             note: value.note.clone(),
             inputs: value.inputs.clone(),
+            reattachments: value.reattachments,
         }
     }
 }
@@ -4,8 +4,13 @@
 use std::collections::HashMap;
 
 use crate::{
-    types::block::output::{dto::OutputMetadataDto, OutputId},
+    types::block::{
+        address::Bech32Address,
+        output::{dto::OutputMetadataDto, OutputId},
+        payload::transaction::TransactionId,
+    },
     wallet::account::{
+        builder::get_first_public_address,
         operations::syncing::options::SyncOptions,
         types::{address::AddressWithUnspentOutputs, InclusionState, OutputData, Transaction},
         Account, AccountAddress,
@@ -30,6 +35,32 @@ impl Account {
         Ok(())
     }
 
+    /// Set a local note for a sent or incoming transaction stored in the account. The note is never sent to the
+    /// network.
+    pub async fn set_transaction_note(&self, transaction_id: &TransactionId, note: &str) -> crate::wallet::Result<()> {
+        let mut account_details = self.details_mut().await;
+
+        let mut transaction_found = false;
+        if let Some(transaction) = account_details.transactions.get_mut(transaction_id) {
+            transaction.note = Some(note.to_string());
+            transaction_found = true;
+        }
+        if let Some(transaction) = account_details.incoming_transactions.get_mut(transaction_id) {
+            transaction.note = Some(note.to_string());
+            transaction_found = true;
+        }
+        if !transaction_found {
+            return Err(crate::wallet::Error::TransactionNotFound(*transaction_id));
+        }
+
+        account_details
+            .transaction_notes
+            .insert(*transaction_id, note.to_string());
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
     /// Update account with newly synced data and emit events for outputs
     pub(crate) async fn update_account(
         &self,
@@ -302,4 +333,46 @@ impl Account {
 
         Ok(())
     }
+
+    /// Update the account with a new coin type. All addresses were derived with the old coin type, so they're dropped
+    /// together with everything that was found for them, only the first public address is generated again.
+    pub(crate) async fn update_account_coin_type(&self, coin_type: u32) -> crate::wallet::Result<()> {
+        let mut account_details = self.details_mut().await;
+        log::debug!(
+            "[UPDATE ACCOUNT WITH COIN TYPE] account {} new coin type: {}",
+            account_details.index,
+            coin_type
+        );
+        let bech32_hrp = match account_details.public_addresses.first() {
+            Some(address) => address.address.hrp.clone(),
+            None => self.client().get_bech32_hrp().await?,
+        };
+        let first_public_address =
+            get_first_public_address(&self.wallet.secret_manager, coin_type, account_details.index).await?;
+
+        account_details.coin_type = coin_type;
+        account_details.public_addresses = vec![AccountAddress {
+            address: Bech32Address::new(bech32_hrp, first_public_address)?,
+            key_index: 0,
+            internal: false,
+            used: false,
+        }];
+        account_details.internal_addresses.clear();
+        account_details.addresses_with_unspent_outputs.clear();
+        account_details.outputs.clear();
+        account_details.locked_outputs.clear();
+        account_details.unspent_outputs.clear();
+        account_details.native_token_foundries.clear();
+
+        #[cfg(feature = "storage")]
+        {
+            log::debug!(
+                "[UPDATE ACCOUNT WITH COIN TYPE] storing account {}",
+                account_details.alias()
+            );
+            self.save(Some(&account_details)).await?;
+        }
+
+        Ok(())
+    }
 }
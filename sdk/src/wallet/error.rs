@@ -8,7 +8,10 @@ use serde::{
     Serialize,
 };
 
-use crate::types::block::payload::transaction::TransactionId;
+use crate::types::block::{
+    output::{AliasId, OutputId},
+    payload::transaction::TransactionId,
+};
 
 /// The wallet error type.
 #[derive(Debug, thiserror::Error)]
@@ -16,12 +19,21 @@ pub enum Error {
     /// Account alias must be unique.
     #[error("can't create account: account alias {0} already exists")]
     AccountAliasAlreadyExists(String),
+    /// Account can't be removed, because it still has funds
+    #[error("can't remove account {0}: it has unspent outputs or pending transactions")]
+    AccountNotEmpty(u32),
     /// Account not found
     #[error("account {0} not found")]
     AccountNotFound(String),
+    /// Syncing an account failed
+    #[error("failed to sync account {account_index}: {error}")]
+    AccountSyncFailed { account_index: u32, error: Box<Self> },
     /// Address not found in account
     #[error("address {0} not found in account")]
     AddressNotFoundInAccount(String),
+    /// Alias not found in unspent outputs
+    #[error("alias {0} not found in unspent outputs")]
+    AliasNotFoundInUnspentOutputs(AliasId),
     /// Errors during backup creation or restoring
     #[error("backup failed {0}")]
     Backup(&'static str),
@@ -34,6 +46,9 @@ pub enum Error {
     /// Client error.
     #[error("`{0}`")]
     Client(Box<crate::client::Error>),
+    /// Coin type can't be changed, because an account still has funds under the current one
+    #[error("can't change coin type: account {0} has unspent outputs or pending transactions")]
+    CoinTypeChangeWithFunds(u32),
     /// Funds are spread over too many outputs
     #[error("funds are spread over too many outputs {output_count}/{output_count_max}, consolidation required")]
     ConsolidationRequired { output_count: usize, output_count_max: u16 },
@@ -43,6 +58,9 @@ pub enum Error {
     /// Custom input error
     #[error("custom input error {0}")]
     CustomInput(String),
+    /// Custom remainder address doesn't belong to the account
+    #[error("remainder address {0} doesn't belong to the account, set `allow_external_remainder` to use it")]
+    ExternalRemainderAddress(String),
     /// Failed to get remainder
     #[error("failed to get remainder address")]
     FailedToGetRemainder,
@@ -55,18 +73,30 @@ pub enum Error {
         new_coin_type: u32,
         existing_coin_type: u32,
     },
+    /// Invalid maximum number of attempts
+    #[error("invalid max attempts: must be greater than 0")]
+    InvalidMaxAttempts,
     /// Invalid mnemonic error
     #[error("invalid mnemonic: {0}")]
     InvalidMnemonic(String),
+    /// Invalid number of outputs to create.
+    #[error("invalid output count {output_count}, must be between 1 and {output_count_max}")]
+    InvalidOutputCount { output_count: u32, output_count_max: u16 },
     /// Invalid output kind.
     #[error("invalid output kind: {0}")]
     InvalidOutputKind(String),
+    /// A recipient of a batch is invalid
+    #[error("invalid recipient {index}: {error}")]
+    InvalidRecipient { index: usize, error: Box<Self> },
     /// IO error. (storage, backup, restore)
     #[error("`{0}`")]
     Io(#[from] std::io::Error),
     /// serde_json error.
     #[error("`{0}`")]
     Json(#[from] serde_json::error::Error),
+    /// Can't remove the last remaining node
+    #[error("can't remove node {0}: it's the last remaining node")]
+    LastNode(String),
     /// Error migrating storage or backup
     #[error("migration failed {0}")]
     Migration(String),
@@ -76,9 +106,15 @@ pub enum Error {
     /// Missing parameter.
     #[error("missing parameter: {0}")]
     MissingParameter(&'static str),
+    /// Too many NFTs to mint a collection in a single transaction
+    #[error("can't mint {item_count} NFTs in one transaction, the maximum is {item_count_max}, mint them in batches")]
+    NftCollectionTooLarge { item_count: usize, item_count_max: usize },
     /// Nft not found in unspent outputs
     #[error("nft not found in unspent outputs")]
     NftNotFoundInUnspentOutputs,
+    /// Node not found in the client options
+    #[error("node {0} not found in the client options")]
+    NodeNotFound(String),
     // TODO more precise error
     /// Voting error
     #[cfg(feature = "participation")]
@@ -90,6 +126,9 @@ pub enum Error {
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     #[error("participation error {0}")]
     Participation(#[from] crate::types::api::plugins::participation::error::Error),
+    /// Output can't be claimed
+    #[error("output {0} can't be claimed: it's unknown, locked or can't be unlocked now")]
+    OutputNotClaimable(OutputId),
     /// No outputs available for consolidating
     #[error(
         "nothing to consolidate: available outputs: {available_outputs}, consolidation threshold: {consolidation_threshold}"
@@ -100,18 +139,29 @@ pub enum Error {
         /// The consolidation threshold.
         consolidation_threshold: usize,
     },
+    /// Stronghold snapshot file not found
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    #[error("stronghold snapshot {} doesn't exist", .0.display())]
+    StrongholdSnapshotNotFound(std::path::PathBuf),
     /// Storage access error.
     #[error("error accessing storage: {0}")]
     Storage(String),
     /// Can't use Wallet API because the storage is encrypted
     #[error("can't perform operation while storage is encrypted; use Wallet::set_storage_password to decrypt storage")]
     StorageIsEncrypted,
+    /// Syncing got aborted
+    #[error("sync aborted")]
+    SyncAborted,
     /// Tokio task join error
     #[error("{0}")]
     TaskJoin(#[from] tokio::task::JoinError),
     /// Transaction not found
     #[error("transaction {0} not found")]
     TransactionNotFound(TransactionId),
+    /// Watch-only accounts have no access to the secret manager
+    #[error("account {0} is watch-only: it can't sign or generate addresses")]
+    WatchOnlyAccount(u32),
 }
 
 // Serialize type with Display error
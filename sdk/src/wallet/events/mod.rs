@@ -36,6 +36,7 @@ impl EventEmitter {
             for event_type in &[
                 WalletEventType::NewOutput,
                 WalletEventType::SpentOutput,
+                WalletEventType::SyncProgress,
                 WalletEventType::TransactionInclusion,
                 WalletEventType::TransactionProgress,
                 WalletEventType::ConsolidationRequired,
@@ -54,14 +55,32 @@ impl EventEmitter {
 
     /// Removes handlers for each given `WalletEventType`.
     /// If no `WalletEventType` is given, handlers will be removed for all event types.
-    pub fn clear(&mut self, events: Vec<WalletEventType>) {
+    /// Returns the amount of removed handlers, a handler registered for multiple event types is counted once per type.
+    pub fn clear(&mut self, events: Vec<WalletEventType>) -> usize {
         // if no event is provided handlers are removed for all event types
         if events.is_empty() {
-            self.handlers.clear();
+            return self.handlers.drain().map(|(_, handlers)| handlers.len()).sum();
         }
+        let mut removed = 0;
         for event in events {
-            self.handlers.remove(&event);
+            if let Some(handlers) = self.handlers.remove(&event) {
+                removed += handlers.len();
+            }
         }
+        removed
+    }
+
+    /// Returns the event types that have registered handlers together with the amount of handlers, ordered by event
+    /// type.
+    pub fn listener_counts(&self) -> Vec<(WalletEventType, usize)> {
+        let mut listener_counts = self
+            .handlers
+            .iter()
+            .filter(|(_, handlers)| !handlers.is_empty())
+            .map(|(event_type, handlers)| (*event_type, handlers.len()))
+            .collect::<Vec<_>>();
+        listener_counts.sort_unstable();
+        listener_counts
     }
 
     /// Invokes all listeners of `event`, passing a reference to `payload` as an
@@ -70,6 +89,7 @@ impl EventEmitter {
         let event_type = match &event {
             WalletEvent::NewOutput(_) => WalletEventType::NewOutput,
             WalletEvent::SpentOutput(_) => WalletEventType::SpentOutput,
+            WalletEvent::SyncProgress(_) => WalletEventType::SyncProgress,
             WalletEvent::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
             WalletEvent::TransactionProgress(_) => WalletEventType::TransactionProgress,
             WalletEvent::ConsolidationRequired => WalletEventType::ConsolidationRequired,
@@ -112,7 +132,7 @@ mod tests {
     };
 
     use super::{
-        types::{TransactionInclusionEvent, TransactionProgressEvent, WalletEvent, WalletEventType},
+        types::{SyncProgressEvent, TransactionInclusionEvent, TransactionProgressEvent, WalletEvent, WalletEventType},
         EventEmitter,
     };
     use crate::{types::block::payload::transaction::TransactionId, wallet::account::types::InclusionState};
@@ -161,18 +181,33 @@ mod tests {
                 inclusion_state: InclusionState::Confirmed,
             }),
         );
+        emitter.emit(
+            0,
+            WalletEvent::SyncProgress(SyncProgressEvent {
+                addresses_scanned: 1,
+                total_addresses: 2,
+                outputs_found: 3,
+            }),
+        );
+
+        assert_eq!(4, event_counter.load(Ordering::SeqCst));
 
-        assert_eq!(3, event_counter.load(Ordering::SeqCst));
+        let remaining_event_types = if cfg!(feature = "ledger_nano") { 6 } else { 5 };
+        let listener_counts = emitter.listener_counts();
+        assert_eq!(listener_counts.len(), remaining_event_types + 1);
+        assert_eq!(listener_counts[0], (WalletEventType::ConsolidationRequired, 3));
+        assert!(listener_counts.contains(&(WalletEventType::TransactionProgress, 2)));
 
         // remove handlers of single event
-        emitter.clear(vec![WalletEventType::ConsolidationRequired]);
+        assert_eq!(3, emitter.clear(vec![WalletEventType::ConsolidationRequired]));
         // emit event of removed type
         emitter.emit(0, WalletEvent::ConsolidationRequired);
 
-        assert_eq!(3, event_counter.load(Ordering::SeqCst));
+        assert_eq!(4, event_counter.load(Ordering::SeqCst));
 
-        // remove handlers of all events
-        emitter.clear(vec![]);
+        // remove handlers of all events, the one for all remaining event types and the one for TransactionProgress
+        assert_eq!(remaining_event_types + 1, emitter.clear(vec![]));
+        assert!(emitter.listener_counts().is_empty());
         // emit events
         emitter.emit(
             0,
@@ -188,7 +223,7 @@ mod tests {
                 inclusion_state: InclusionState::Confirmed,
             }),
         );
-        assert_eq!(3, event_counter.load(Ordering::SeqCst));
+        assert_eq!(4, event_counter.load(Ordering::SeqCst));
 
         // listen to a single event
         let event_counter_clone = Arc::clone(&event_counter);
@@ -200,6 +235,6 @@ mod tests {
         for _ in 0..1_000_000 {
             emitter.emit(0, WalletEvent::ConsolidationRequired);
         }
-        assert_eq!(1_000_003, event_counter.load(Ordering::SeqCst));
+        assert_eq!(1_000_004, event_counter.load(Ordering::SeqCst));
     }
 }
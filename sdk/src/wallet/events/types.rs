@@ -32,11 +32,12 @@ pub enum WalletEvent {
     LedgerAddressGeneration(AddressData),
     NewOutput(Box<NewOutputEvent>),
     SpentOutput(Box<SpentOutputEvent>),
+    SyncProgress(SyncProgressEvent),
     TransactionInclusion(TransactionInclusionEvent),
     TransactionProgress(TransactionProgressEvent),
 }
 
-#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum WalletEventType {
     ConsolidationRequired,
     #[cfg(feature = "ledger_nano")]
@@ -44,6 +45,7 @@ pub enum WalletEventType {
     LedgerAddressGeneration,
     NewOutput,
     SpentOutput,
+    SyncProgress,
     TransactionInclusion,
     TransactionProgress,
 }
@@ -58,6 +60,7 @@ impl TryFrom<&str> for WalletEventType {
             "LedgerAddressGeneration" => Self::LedgerAddressGeneration,
             "NewOutput" => Self::NewOutput,
             "SpentOutput" => Self::SpentOutput,
+            "SyncProgress" => Self::SyncProgress,
             "TransactionInclusion" => Self::TransactionInclusion,
             "TransactionProgress" => Self::TransactionProgress,
             _ => return Err(format!("invalid event type {value}")),
@@ -85,6 +88,18 @@ pub struct SpentOutputEvent {
     pub output: OutputDataDto,
 }
 
+/// Progress of scanning the account addresses for outputs during syncing.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncProgressEvent {
+    /// Amount of addresses for which the output ids were requested so far.
+    pub addresses_scanned: usize,
+    /// Amount of addresses that get synced.
+    pub total_addresses: usize,
+    /// Amount of output ids found so far.
+    pub outputs_found: usize,
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionInclusionEvent {
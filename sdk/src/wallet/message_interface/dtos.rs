@@ -49,6 +49,12 @@ pub struct AccountDetailsDto {
     /// Foundries for native tokens in outputs
     #[serde(default)]
     pub native_token_foundries: HashMap<FoundryId, FoundryOutputDto>,
+    /// Whether the account is watch-only
+    #[serde(default)]
+    pub watch_only: bool,
+    /// Local notes for transactions
+    #[serde(default)]
+    pub transaction_notes: HashMap<TransactionId, String>,
 }
 
 impl From<&AccountDetails> for AccountDetailsDto {
@@ -87,6 +93,8 @@ impl From<&AccountDetails> for AccountDetailsDto {
                 .iter()
                 .map(|(id, foundry)| (*id, FoundryOutputDto::from(foundry)))
                 .collect(),
+            watch_only: *value.watch_only(),
+            transaction_notes: value.transaction_notes().clone(),
         }
     }
 }
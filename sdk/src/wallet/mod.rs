@@ -33,18 +33,20 @@ pub mod storage;
 /// The module for spawning tasks on a thread
 pub(crate) mod task;
 
+#[cfg(feature = "stronghold")]
+pub use self::wallet::BackupContents;
 pub use self::{
     account::{
         operations::transaction::high_level::{
             minting::{mint_native_token::MintNativeTokenParams, mint_nfts::MintNftParams},
-            send_amount::SendAmountParams,
+            send_amount::{RecipientParams, SendAmountParams},
             send_native_tokens::SendNativeTokensParams,
             send_nft::SendNftParams,
         },
         Account,
     },
     error::Error,
-    wallet::{Wallet, WalletBuilder},
+    wallet::{BackgroundSyncStatus, Wallet, WalletBuilder, WalletDataJson},
 };
 
 /// The wallet Result type.
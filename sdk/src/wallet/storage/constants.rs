@@ -19,10 +19,13 @@ pub(crate) const WALLET_INDEXATION_KEY: &str = "iota-wallet-account-manager";
 
 pub(crate) const SECRET_MANAGER_KEY: &str = "secret_manager";
 
+pub(crate) const WALLET_SYNC_OPTIONS_KEY: &str = "iota-wallet-sync-options";
+
 pub(crate) const ACCOUNTS_INDEXATION_KEY: &str = "iota-wallet-accounts";
 pub(crate) const ACCOUNT_INDEXATION_KEY: &str = "iota-wallet-account-";
 
 pub(crate) const ACCOUNT_SYNC_OPTIONS: &str = "sync-options";
+pub(crate) const ACCOUNT_RETRY_OPTIONS: &str = "retry-options";
 
 pub(crate) const DATABASE_SCHEMA_VERSION: u8 = 1;
 pub(crate) const DATABASE_SCHEMA_VERSION_KEY: &str = "database-schema-version";
@@ -9,7 +9,7 @@ use tokio::sync::RwLock;
 use crate::{
     client::secret::{SecretManager, SecretManagerDto},
     wallet::{
-        account::{AccountDetails, SyncOptions},
+        account::{AccountDetails, RetryOptions, SyncOptions},
         migration::migrate_storage,
         storage::{constants::*, Storage, StorageAdapter},
         WalletBuilder,
@@ -179,12 +179,40 @@ impl StorageManager {
         self.storage
             .remove(&format!("{ACCOUNT_INDEXATION_KEY}{account_index}"))
             .await?;
+        // Also remove the data stored per account index, so a new account with the same index doesn't inherit it
+        self.storage
+            .remove(&format!(
+                "{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_OPTIONS}"
+            ))
+            .await?;
+        self.storage
+            .remove(&format!(
+                "{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_RETRY_OPTIONS}"
+            ))
+            .await?;
+        #[cfg(feature = "participation")]
+        {
+            self.storage
+                .remove(&format!("{PARTICIPATION_EVENTS}{account_index}"))
+                .await?;
+            self.storage
+                .remove(&format!("{PARTICIPATION_CACHED_OUTPUTS}{account_index}"))
+                .await?;
+        }
         self.account_indexes.retain(|a| a != &account_index);
         self.storage
             .set(ACCOUNTS_INDEXATION_KEY, self.account_indexes.clone())
             .await
     }
 
+    pub async fn set_wallet_default_sync_options(&self, sync_options: &SyncOptions) -> crate::wallet::Result<()> {
+        self.storage.set(WALLET_SYNC_OPTIONS_KEY, sync_options.clone()).await
+    }
+
+    pub async fn get_wallet_default_sync_options(&self) -> crate::wallet::Result<Option<SyncOptions>> {
+        self.storage.get(WALLET_SYNC_OPTIONS_KEY).await
+    }
+
     pub async fn set_default_sync_options(
         &self,
         account_index: u32,
@@ -198,6 +226,20 @@ impl StorageManager {
         let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_OPTIONS}");
         self.storage.get(&key).await
     }
+
+    pub async fn set_default_retry_options(
+        &self,
+        account_index: u32,
+        retry_options: &RetryOptions,
+    ) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_RETRY_OPTIONS}");
+        self.storage.set(&key, *retry_options).await
+    }
+
+    pub async fn get_default_retry_options(&self, account_index: u32) -> crate::wallet::Result<Option<RetryOptions>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_RETRY_OPTIONS}");
+        self.storage.get(&key).await
+    }
 }
 
 #[cfg(test)]
@@ -245,8 +287,13 @@ mod tests {
         assert_eq!(accounts.len(), 1);
         assert_eq!(accounts[0].alias(), "Alice");
 
+        storage_manager
+            .set_default_sync_options(0, &SyncOptions::default())
+            .await
+            .unwrap();
         storage_manager.remove_account(0).await.unwrap();
         assert!(storage_manager.get_accounts().await.unwrap().is_empty());
+        assert!(storage_manager.get_default_sync_options(0).await.unwrap().is_none());
     }
 
     #[tokio::test]
@@ -10,7 +10,7 @@ use std::{collections::HashSet, path::PathBuf, sync::atomic::Ordering};
 
 use futures::{future::try_join_all, FutureExt};
 use serde::{Deserialize, Serialize};
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 
 #[cfg(feature = "events")]
 use crate::wallet::events::EventEmitter;
@@ -194,8 +194,20 @@ impl WalletBuilder {
         unlock_unused_inputs(&mut accounts)?;
         #[cfg(not(feature = "storage"))]
         let accounts = Vec::new();
+
+        #[cfg(feature = "storage")]
+        let default_sync_options = storage_manager
+            .get_wallet_default_sync_options()
+            .await?
+            .unwrap_or_default();
+        #[cfg(not(feature = "storage"))]
+        let default_sync_options = Default::default();
+
         let wallet_inner = Arc::new(WalletInner {
             background_syncing_status: AtomicUsize::new(0),
+            sync_abort_requests: AtomicUsize::new(0),
+            background_syncing_interval: Mutex::new(None),
+            last_background_sync_timestamp: Mutex::new(None),
             client: self
                 .client_options
                 .clone()
@@ -208,6 +220,7 @@ impl WalletBuilder {
             secret_manager: self
                 .secret_manager
                 .ok_or(crate::wallet::Error::MissingParameter("secret_manager"))?,
+            default_sync_options: Mutex::new(default_sync_options),
             #[cfg(feature = "events")]
             event_emitter,
             #[cfg(feature = "storage")]
@@ -4,16 +4,24 @@
 pub(crate) mod builder;
 pub(crate) mod operations;
 
-use std::sync::{
-    atomic::{AtomicU32, AtomicUsize},
-    Arc,
+use std::{
+    sync::{
+        atomic::{AtomicU32, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
 };
 
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 
 #[cfg(feature = "storage")]
 use self::builder::StorageOptions;
-pub use self::builder::WalletBuilder;
+#[cfg(feature = "stronghold")]
+pub use self::operations::stronghold_backup::BackupContents;
+pub use self::{
+    builder::WalletBuilder,
+    operations::{background_syncing::BackgroundSyncStatus, import_accounts::WalletDataJson},
+};
 #[cfg(feature = "events")]
 use crate::wallet::events::{
     types::{Event, WalletEventType},
@@ -23,7 +31,13 @@ use crate::wallet::events::{
 use crate::wallet::storage::manager::StorageManager;
 use crate::{
     client::{secret::SecretManager, verify_mnemonic, Client},
-    wallet::account::{builder::AccountBuilder, operations::syncing::SyncOptions, types::AccountBalance, Account},
+    wallet::account::{
+        builder::AccountBuilder,
+        constants::PARALLEL_ACCOUNT_SYNCS_AMOUNT,
+        operations::syncing::SyncOptions,
+        types::{AccountBalance, AccountIdentifier},
+        Account,
+    },
 };
 
 /// The wallet, used to create and get accounts. One wallet can hold many accounts, but they should
@@ -60,9 +74,16 @@ impl Wallet {
 pub struct WalletInner {
     // 0 = not running, 1 = running, 2 = stopping
     pub(crate) background_syncing_status: AtomicUsize,
+    pub(crate) background_syncing_interval: Mutex<Option<Duration>>,
+    // unix timestamp in milliseconds of the last background syncing cycle that synced all accounts
+    pub(crate) last_background_sync_timestamp: Mutex<Option<u64>>,
+    // number of pending abort requests, syncs abort while it's not 0
+    pub(crate) sync_abort_requests: AtomicUsize,
     pub(crate) client: Client,
     pub(crate) coin_type: AtomicU32,
     pub(crate) secret_manager: Arc<RwLock<SecretManager>>,
+    // the default SyncOptions for accounts that don't have their own
+    pub(crate) default_sync_options: Mutex<SyncOptions>,
     #[cfg(feature = "events")]
     pub(crate) event_emitter: tokio::sync::RwLock<EventEmitter>,
     #[cfg(feature = "storage")]
@@ -125,6 +146,99 @@ impl Wallet {
         Ok(())
     }
 
+    /// Removes an account, the indexes of the other accounts stay the same.
+    /// Fails if the account has unspent outputs or pending transactions, unless `force` is set.
+    pub async fn remove_account<I: Into<AccountIdentifier> + Send>(
+        &self,
+        identifier: I,
+        force: bool,
+    ) -> crate::wallet::Result<()> {
+        let account_id = identifier.into();
+        // Hold the lock for the accounts and the details of the account while checking and removing it, so it can't get
+        // funded or send a transaction in between
+        let mut accounts = self.accounts.write().await;
+
+        for i in 0..accounts.len() {
+            let account = accounts[i].clone();
+            let account_details = account.details_mut().await;
+            let matches = match &account_id {
+                AccountIdentifier::Index(index) => account_details.index() == index,
+                AccountIdentifier::Alias(alias) => account_details.alias() == alias,
+            };
+            if !matches {
+                continue;
+            }
+
+            if !force
+                && (!account_details.unspent_outputs().is_empty() || !account_details.pending_transactions().is_empty())
+            {
+                return Err(crate::wallet::Error::AccountNotEmpty(*account_details.index()));
+            }
+
+            #[cfg(feature = "storage")]
+            self.storage_manager
+                .write()
+                .await
+                .remove_account(*account_details.index())
+                .await?;
+
+            let _ = accounts.remove(i);
+
+            return Ok(());
+        }
+
+        Err(crate::wallet::Error::AccountNotFound(serde_json::to_string(
+            &account_id,
+        )?))
+    }
+
+    /// Sets the coin type of the wallet and all its accounts.
+    ///
+    /// All existing addresses were derived with the old coin type and become stale: they're removed together with the
+    /// outputs found for them, and only the first public address of each account is generated again. The transaction
+    /// history and transaction notes are kept. Other addresses need to be regenerated and the accounts synced
+    /// afterwards.
+    /// Fails if an account has unspent outputs or pending transactions, unless `force` is set. Always fails if the
+    /// wallet has a watch-only account, also with `force`, because its addresses can't be derived with the new coin
+    /// type; such accounts need to be removed first.
+    pub async fn set_coin_type(&self, coin_type: u32, force: bool) -> crate::wallet::Result<()> {
+        let accounts = self.accounts.write().await;
+
+        // The addresses of watch-only accounts can't be derived again with the new coin type
+        for account in accounts.iter() {
+            let account_details = account.details().await;
+            if *account_details.watch_only() {
+                return Err(crate::wallet::Error::WatchOnlyAccount(*account_details.index()));
+            }
+        }
+
+        if !force {
+            for account in accounts.iter() {
+                let account_details = account.details().await;
+                if !account_details.unspent_outputs().is_empty() || !account_details.pending_transactions().is_empty() {
+                    return Err(crate::wallet::Error::CoinTypeChangeWithFunds(*account_details.index()));
+                }
+            }
+        }
+
+        for account in accounts.iter() {
+            account.update_account_coin_type(coin_type).await?;
+        }
+
+        self.coin_type.store(coin_type, Ordering::Relaxed);
+
+        #[cfg(feature = "storage")]
+        {
+            self.storage_manager
+                .read()
+                .await
+                .save_wallet_data(&WalletBuilder::from_wallet(self).await)
+                .await?;
+        }
+
+        Ok(())
+    }
+
     /// Get the balance of all accounts added together
     pub async fn balance(&self) -> crate::wallet::Result<AccountBalance> {
         let mut balance = AccountBalance::default();
@@ -137,16 +251,71 @@ impl Wallet {
         Ok(balance)
     }
 
-    /// Sync all accounts
+    /// Set the fallback SyncOptions for all accounts, also for accounts that will be created later.
+    /// If storage is enabled, will persist during restarts.
+    pub async fn set_default_sync_options(&self, options: SyncOptions) -> crate::wallet::Result<()> {
+        let accounts = self.accounts.read().await;
+
+        #[cfg(feature = "storage")]
+        self.storage_manager
+            .read()
+            .await
+            .set_wallet_default_sync_options(&options)
+            .await?;
+
+        *self.default_sync_options.lock().await = options.clone();
+
+        for account in accounts.iter() {
+            account.set_default_sync_options(options.clone()).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Sync all accounts, fails with the index of the first account that couldn't be synced
     pub async fn sync(&self, options: Option<SyncOptions>) -> crate::wallet::Result<AccountBalance> {
         let mut balance = AccountBalance::default();
 
         for account in self.accounts.read().await.iter() {
-            balance += account.sync(options.clone()).await?;
+            let account_index = *account.details().await.index();
+            balance +=
+                account
+                    .sync(options.clone())
+                    .await
+                    .map_err(|error| crate::wallet::Error::AccountSyncFailed {
+                        account_index,
+                        error: Box::new(error),
+                    })?;
         }
 
         Ok(balance)
     }
+
+    /// Sync all accounts concurrently, a few at a time to not overload the node. An account that fails to sync doesn't
+    /// stop the others, so the result is returned for each account, ordered by account index.
+    pub async fn sync_accounts(
+        &self,
+        options: Option<SyncOptions>,
+    ) -> Vec<(u32, crate::wallet::Result<AccountBalance>)> {
+        let mut accounts = Vec::new();
+        for account in self.accounts.read().await.iter() {
+            accounts.push((*account.details().await.index(), account.clone()));
+        }
+        accounts.sort_by_key(|(index, _)| *index);
+
+        let mut results = Vec::with_capacity(accounts.len());
+        for accounts_chunk in accounts.chunks(PARALLEL_ACCOUNT_SYNCS_AMOUNT) {
+            results.extend(
+                futures::future::join_all(accounts_chunk.iter().map(|(account_index, account)| {
+                    let options = options.clone();
+                    async move { (*account_index, account.sync(options).await) }
+                }))
+                .await,
+            );
+        }
+
+        results
+    }
 }
 
 impl WalletInner {
@@ -166,12 +335,19 @@ impl WalletInner {
         emitter.on(events, handler);
     }
 
-    /// Remove wallet event listeners, empty vec will remove all listeners
+    /// Remove wallet event listeners, empty vec will remove all listeners. Returns the amount of removed listeners.
     #[cfg(feature = "events")]
     #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
-    pub async fn clear_listeners(&self, events: Vec<WalletEventType>) {
+    pub async fn clear_listeners(&self, events: Vec<WalletEventType>) -> usize {
         let mut emitter = self.event_emitter.write().await;
-        emitter.clear(events);
+        emitter.clear(events)
+    }
+
+    /// Get the event types that have listeners registered, with the amount of listeners for each type.
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    pub async fn active_listeners(&self) -> Vec<(WalletEventType, usize)> {
+        self.event_emitter.read().await.listener_counts()
     }
 
     /// Generates a new random mnemonic.
@@ -5,11 +5,25 @@ use std::{sync::atomic::Ordering, time::Duration};
 
 use tokio::time::sleep;
 
-use crate::wallet::{account::operations::syncing::SyncOptions, Wallet};
+use crate::wallet::{
+    account::operations::syncing::{SyncAbortRequest, SyncOptions},
+    Wallet,
+};
 
 /// The default interval for background syncing
 pub(crate) const DEFAULT_BACKGROUNDSYNCING_INTERVAL: Duration = Duration::from_secs(7);
 
+/// The status of the background syncing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackgroundSyncStatus {
+    /// If the background syncing is running
+    pub running: bool,
+    /// The interval of the background syncing, if it's running
+    pub interval: Option<Duration>,
+    /// The unix timestamp in milliseconds of the last cycle in which all accounts got synced successfully
+    pub last_sync_timestamp: Option<u64>,
+}
+
 impl Wallet {
     /// Start the background syncing process for all accounts, default interval is 7 seconds
     pub async fn start_background_syncing(
@@ -28,6 +42,7 @@ impl Wallet {
         }
 
         self.background_syncing_status.store(1, Ordering::Relaxed);
+        *self.background_syncing_interval.lock().await = Some(interval.unwrap_or(DEFAULT_BACKGROUNDSYNCING_INTERVAL));
         let wallet = self.clone();
         let _background_syncing = std::thread::spawn(move || {
             #[cfg(not(target_family = "wasm"))]
@@ -43,6 +58,7 @@ impl Wallet {
             runtime.block_on(async {
                 'outer: loop {
                     log::debug!("[background_syncing]: syncing accounts");
+                    let mut synced_all_accounts = true;
                     for account in wallet.accounts.read().await.iter() {
                         // Check if the process should stop before syncing each account so it stops faster
                         if wallet.background_syncing_status.load(Ordering::Relaxed) == 2 {
@@ -51,9 +67,16 @@ impl Wallet {
                         }
                         match account.sync(options.clone()).await {
                             Ok(_) => {}
-                            Err(err) => log::debug!("[background_syncing] error: {}", err),
+                            Err(err) => {
+                                synced_all_accounts = false;
+                                log::debug!("[background_syncing] error: {}", err)
+                            }
                         };
                     }
+                    if synced_all_accounts {
+                        *wallet.last_background_sync_timestamp.lock().await =
+                            Some(crate::utils::unix_timestamp_now().as_millis() as u64);
+                    }
                     // split interval syncing to seconds so stopping the process doesn't have to wait long
                     let seconds = interval.unwrap_or(DEFAULT_BACKGROUNDSYNCING_INTERVAL).as_secs();
                     for _ in 0..seconds {
@@ -64,6 +87,7 @@ impl Wallet {
                         sleep(Duration::from_secs(1)).await;
                     }
                 }
+                *wallet.background_syncing_interval.lock().await = None;
                 wallet.background_syncing_status.store(0, Ordering::Relaxed);
                 log::debug!("[background_syncing]: stopped");
             });
@@ -71,6 +95,27 @@ impl Wallet {
         Ok(())
     }
 
+    /// Abort the syncs of all accounts that are in progress at the next address batch, their partial results are
+    /// discarded. Returns once no account is syncing anymore.
+    pub async fn abort_sync(&self) -> crate::wallet::Result<()> {
+        log::debug!("[abort_sync]");
+        let _abort_request = SyncAbortRequest::new(&self.sync_abort_requests);
+        // An account holds the last_synced lock while syncing, so once we got it for every account, no sync is running
+        for account in self.accounts.read().await.iter() {
+            drop(account.last_synced.lock().await);
+        }
+        Ok(())
+    }
+
+    /// Get the status of the background syncing
+    pub async fn background_syncing_status(&self) -> BackgroundSyncStatus {
+        BackgroundSyncStatus {
+            running: self.background_syncing_status.load(Ordering::Relaxed) == 1,
+            interval: *self.background_syncing_interval.lock().await,
+            last_sync_timestamp: *self.last_background_sync_timestamp.lock().await,
+        }
+    }
+
     /// Stop the background syncing of the accounts
     pub async fn stop_background_syncing(&self) -> crate::wallet::Result<()> {
         log::debug!("[stop_background_syncing]");
@@ -90,3 +135,46 @@ impl Wallet {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        client::{
+            constants::SHIMMER_COIN_TYPE,
+            secret::{mnemonic::MnemonicSecretManager, SecretManager},
+            Client,
+        },
+        wallet::{ClientOptions, Wallet},
+    };
+
+    #[tokio::test]
+    async fn dropped_abort_sync() {
+        let storage_path = "test-storage/dropped_abort_sync";
+        let secret_manager = MnemonicSecretManager::try_from_mnemonic(&Client::generate_mnemonic().unwrap()).unwrap();
+        #[allow(unused_mut)]
+        let mut wallet_builder = Wallet::builder()
+            .with_secret_manager(SecretManager::Mnemonic(secret_manager))
+            .with_client_options(ClientOptions::new().with_node("http://localhost:14265").unwrap())
+            .with_coin_type(SHIMMER_COIN_TYPE);
+        #[cfg(feature = "storage")]
+        {
+            wallet_builder = wallet_builder.with_storage_path(storage_path);
+        }
+        let wallet = wallet_builder.finish().await.unwrap();
+        let account = wallet.create_account().finish().await.unwrap();
+
+        // Hold the lock like a sync in progress does, so abort_sync() can't finish and gets dropped by the timeout
+        let last_synced = account.last_synced.lock().await;
+        assert!(tokio::time::timeout(Duration::from_millis(100), wallet.abort_sync())
+            .await
+            .is_err());
+        drop(last_synced);
+
+        // The abort request got removed together with the dropped future, so syncing isn't aborted anymore
+        assert!(account.check_sync_aborted().is_ok());
+
+        std::fs::remove_dir_all(storage_path).ok();
+    }
+}
@@ -8,12 +8,12 @@ use crate::wallet::WalletBuilder;
 use crate::{
     client::{
         node_manager::{
-            builder::NodeManagerBuilder,
+            builder::{validate_url, NodeManagerBuilder},
             node::{Node, NodeAuth, NodeDto},
         },
         Client, ClientBuilder, NodeInfoWrapper,
     },
-    wallet::Wallet,
+    wallet::{Error, Wallet},
     Url,
 };
 
@@ -36,6 +36,8 @@ impl Wallet {
             remote_pow_timeout,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count,
+            #[cfg(target_family = "wasm")]
+            network_info_cache_ttl,
         } = client_options;
         self.client
             .update_node_manager(node_manager_builder.build(HashMap::new()))
@@ -47,6 +49,10 @@ impl Wallet {
         {
             *self.client.pow_worker_count.write().await = pow_worker_count;
         }
+        #[cfg(target_family = "wasm")]
+        {
+            *self.client.network_info_cache_ttl.write().await = network_info_cache_ttl;
+        }
         #[cfg(feature = "mqtt")]
         {
             *self.client.mqtt.broker_options.write().await = broker_options;
@@ -61,6 +67,69 @@ impl Wallet {
         Ok(node_info_wrapper)
     }
 
+    /// Returns the configured nodes, starting with the primary node if one is set, including disabled nodes.
+    pub async fn client_nodes(&self) -> Vec<Node> {
+        let node_manager = self.client.node_manager.read().await;
+        let mut other_nodes = node_manager.nodes.iter().collect::<Vec<_>>();
+        other_nodes.sort_unstable_by(|a, b| a.url.as_str().cmp(b.url.as_str()));
+
+        let mut nodes: Vec<Node> = Vec::new();
+        for node in node_manager.primary_node.iter().chain(other_nodes) {
+            if !nodes.iter().any(|n| n.url == node.url) {
+                nodes.push(node.clone());
+            }
+        }
+        nodes
+    }
+
+    /// Query all configured nodes concurrently and return for each node url whether it's healthy and its confirmed
+    /// milestone index. Nodes that can't be reached within the api timeout are returned as unhealthy without a
+    /// milestone index. Disabled nodes are skipped.
+    pub async fn get_nodes_health(&self) -> Vec<(Url, bool, Option<u32>)> {
+        log::debug!("[get_nodes_health]");
+        let nodes = self
+            .client_nodes()
+            .await
+            .into_iter()
+            .filter(|node| !node.disabled)
+            .collect::<Vec<_>>();
+        let timeout = self.client.get_timeout().await;
+
+        let tasks = nodes.into_iter().map(|node| async move {
+            let node_info = Client::get_node_info(node.url.as_str(), node.auth.clone());
+            #[cfg(not(target_family = "wasm"))]
+            let node_info = tokio::time::timeout(timeout, node_info).await.ok();
+            #[cfg(target_family = "wasm")]
+            let node_info = match futures::future::select(
+                Box::pin(node_info),
+                gloo_timers::future::TimeoutFuture::new(timeout.as_millis() as u32),
+            )
+            .await
+            {
+                futures::future::Either::Left((node_info, _)) => Some(node_info),
+                futures::future::Either::Right(_) => None,
+            };
+
+            match node_info {
+                Some(Ok(info)) => (
+                    node.url,
+                    info.status.is_healthy,
+                    Some(info.status.confirmed_milestone.index),
+                ),
+                Some(Err(err)) => {
+                    log::debug!("[get_nodes_health] couldn't get node info from {}: {err}", node.url);
+                    (node.url, false, None)
+                }
+                None => {
+                    log::debug!("[get_nodes_health] node {} timed out", node.url);
+                    (node.url, false, None)
+                }
+            }
+        });
+
+        futures::future::join_all(tasks).await
+    }
+
     /// Update the authentication for a node.
     pub async fn update_node_auth(&self, url: Url, auth: Option<NodeAuth>) -> crate::wallet::Result<()> {
         log::debug!("[update_node_auth]");
@@ -155,4 +224,98 @@ impl Wallet {
 
         Ok(())
     }
+
+    /// Add a node to the client options without changing the other options. If a node with the same url is already
+    /// configured, its authentication and disabled state are updated.
+    pub async fn add_node(&self, url: Url, auth: Option<NodeAuth>, disabled: bool) -> crate::wallet::Result<()> {
+        log::debug!("[add_node]");
+        let url = validate_url(url)?;
+        let mut node_manager_builder = NodeManagerBuilder::from(&*self.client.node_manager.read().await);
+
+        node_manager_builder.nodes.retain(|node| Node::from(node).url != url);
+        node_manager_builder
+            .nodes
+            .insert(NodeDto::Node(Node { url, auth, disabled }));
+
+        self.update_node_manager_builder(node_manager_builder).await
+    }
+
+    /// Remove a node from the client options without changing the other options. Errors if the node isn't configured
+    /// or if it's the last remaining node.
+    pub async fn remove_node(&self, url: Url) -> crate::wallet::Result<()> {
+        log::debug!("[remove_node]");
+        let mut node_manager_builder = NodeManagerBuilder::from(&*self.client.node_manager.read().await);
+
+        let nodes_count = node_manager_builder.nodes.len();
+        node_manager_builder.nodes.retain(|node| Node::from(node).url != url);
+        let mut removed = node_manager_builder.nodes.len() != nodes_count;
+
+        if node_manager_builder
+            .primary_node
+            .as_ref()
+            .map_or(false, |node| Node::from(node).url == url)
+        {
+            node_manager_builder.primary_node = None;
+            removed = true;
+        }
+
+        if !removed {
+            return Err(Error::NodeNotFound(url.to_string()));
+        }
+        if node_manager_builder.nodes.is_empty() && node_manager_builder.primary_node.is_none() {
+            return Err(Error::LastNode(url.to_string()));
+        }
+
+        self.update_node_manager_builder(node_manager_builder).await
+    }
+
+    /// Set one of the configured nodes as primary node, so it's used first for all requests and the other nodes are
+    /// only used as fallback. A previous primary node stays configured as a regular node.
+    pub async fn set_primary_node(&self, url: Url) -> crate::wallet::Result<()> {
+        log::debug!("[set_primary_node]");
+        let mut node_manager_builder = NodeManagerBuilder::from(&*self.client.node_manager.read().await);
+
+        let node = node_manager_builder
+            .primary_node
+            .iter()
+            .chain(node_manager_builder.nodes.iter())
+            .map(Node::from)
+            .find(|node| node.url == url)
+            .ok_or_else(|| Error::NodeNotFound(url.to_string()))?;
+
+        if let Some(previous_primary_node) = node_manager_builder.primary_node.take() {
+            let previous_url = Node::from(&previous_primary_node).url;
+            if !node_manager_builder
+                .nodes
+                .iter()
+                .any(|node| Node::from(node).url == previous_url)
+            {
+                node_manager_builder.nodes.insert(previous_primary_node);
+            }
+        }
+        node_manager_builder.primary_node = Some(NodeDto::Node(node));
+
+        self.update_node_manager_builder(node_manager_builder).await
+    }
+
+    async fn update_node_manager_builder(&self, node_manager_builder: NodeManagerBuilder) -> crate::wallet::Result<()> {
+        self.client
+            .update_node_manager(node_manager_builder.build(HashMap::new()))
+            .await?;
+
+        #[cfg(feature = "storage")]
+        {
+            self.storage_manager
+                .read()
+                .await
+                .save_wallet_data(&WalletBuilder::from_wallet(self).await)
+                .await?;
+        }
+
+        for account in self.accounts.write().await.iter_mut() {
+            account.update_account_bech32_hrp().await?;
+        }
+
+        Ok(())
+    }
 }
@@ -1,9 +1,12 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::wallet::{
-    account::{types::AccountIdentifier, Account},
-    Wallet,
+use crate::{
+    types::block::address::Bech32Address,
+    wallet::{
+        account::{types::AccountIdentifier, Account},
+        Wallet,
+    },
 };
 
 impl Wallet {
@@ -40,4 +43,24 @@ impl Wallet {
             &account_id,
         )?))
     }
+
+    /// Find the account that generated the given address, public and internal addresses are considered.
+    pub async fn find_account_for_address(&self, address: &Bech32Address) -> crate::wallet::Result<Option<Account>> {
+        let accounts = self.accounts.read().await;
+
+        for account in accounts.iter() {
+            let account_details = account.details().await;
+
+            if account_details
+                .public_addresses()
+                .iter()
+                .chain(account_details.internal_addresses())
+                .any(|account_address| account_address.address() == address)
+            {
+                return Ok(Some(account.clone()));
+            }
+        }
+
+        Ok(None)
+    }
 }
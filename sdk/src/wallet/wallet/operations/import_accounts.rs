@@ -0,0 +1,72 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, sync::atomic::Ordering};
+
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::{
+    account::{Account, AccountDetails},
+    Error, Wallet,
+};
+
+/// Plain JSON data of the accounts of a wallet, without any secret material.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletDataJson {
+    /// The accounts with their addresses, outputs and transactions
+    pub accounts: Vec<AccountDetails>,
+}
+
+impl Wallet {
+    /// Imports accounts as watch-only accounts, they get the indexes after the existing accounts. Watch-only accounts
+    /// can be synced, but they can't sign or generate addresses, because they weren't created from the secret manager
+    /// of the wallet.
+    pub async fn import_accounts(&self, data: WalletDataJson) -> crate::wallet::Result<Vec<Account>> {
+        log::debug!("[IMPORT ACCOUNTS] importing {} accounts", data.accounts.len());
+        let mut accounts = self.accounts.write().await;
+        let coin_type = self.coin_type.load(Ordering::Relaxed);
+
+        // Use the index after the highest existing one, accounts can be removed, so there can be gaps
+        let mut account_index = 0;
+        let mut aliases = HashSet::new();
+        for account in accounts.iter() {
+            let account_details = account.details().await;
+            account_index = account_index.max(*account_details.index() + 1);
+            aliases.insert(account_details.alias().to_lowercase());
+        }
+
+        // Check all accounts before importing any, so that either all or none of them are imported
+        for account_details in &data.accounts {
+            if *account_details.coin_type() != coin_type {
+                return Err(Error::InvalidCoinType {
+                    new_coin_type: *account_details.coin_type(),
+                    existing_coin_type: coin_type,
+                });
+            }
+            if !aliases.insert(account_details.alias().to_lowercase()) {
+                return Err(Error::AccountAliasAlreadyExists(account_details.alias().clone()));
+            }
+        }
+
+        let mut imported_accounts = Vec::with_capacity(data.accounts.len());
+        for mut account_details in data.accounts {
+            log::debug!(
+                "[IMPORT ACCOUNTS] importing account {} with index {}",
+                account_details.alias(),
+                account_index
+            );
+            account_details.set_index(account_index);
+            account_details.set_watch_only(true);
+            account_index += 1;
+
+            let account = Account::new(account_details, self.inner.clone()).await?;
+            #[cfg(feature = "storage")]
+            account.save(None).await?;
+            accounts.push(account.clone());
+            imported_accounts.push(account);
+        }
+
+        Ok(imported_accounts)
+    }
+}
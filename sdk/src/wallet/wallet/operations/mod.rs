@@ -6,6 +6,7 @@ pub(crate) mod address_generation;
 pub(crate) mod background_syncing;
 pub(crate) mod client;
 pub(crate) mod get_account;
+pub(crate) mod import_accounts;
 #[cfg(feature = "ledger_nano")]
 pub(crate) mod ledger_nano;
 #[cfg(feature = "stronghold")]
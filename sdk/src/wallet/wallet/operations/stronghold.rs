@@ -1,9 +1,18 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::time::Duration;
+use std::{path::PathBuf, sync::atomic::Ordering, time::Duration};
 
-use crate::{client::secret::SecretManager, wallet::Wallet};
+use super::stronghold_backup::stronghold_snapshot::COIN_TYPE_KEY;
+#[cfg(feature = "storage")]
+use crate::wallet::WalletBuilder;
+use crate::{
+    client::{
+        secret::{stronghold::StrongholdSecretManager, SecretManage, SecretManager},
+        storage::StorageProvider,
+    },
+    wallet::Wallet,
+};
 
 impl Wallet {
     /// Sets the Stronghold password
@@ -27,6 +36,88 @@ impl Wallet {
         Ok(())
     }
 
+    /// Switches the Stronghold secret manager to the snapshot at another path, for example after the file got moved.
+    /// The snapshot is opened with the current password. Fails if the file doesn't exist, if it stores another coin
+    /// type than the one of the wallet or if it doesn't contain the seed of the existing accounts.
+    pub async fn set_stronghold_snapshot_path(
+        &self,
+        snapshot_path: PathBuf,
+        password: &str,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("[set_stronghold_snapshot_path]");
+        if !snapshot_path.is_file() {
+            return Err(crate::wallet::Error::StrongholdSnapshotNotFound(snapshot_path));
+        }
+
+        let timeout = match &*self.secret_manager.read().await {
+            SecretManager::Stronghold(stronghold) => stronghold.get_timeout(),
+            _ => return Err(crate::client::Error::SecretManagerMismatch.into()),
+        };
+
+        let mut new_stronghold = StrongholdSecretManager::builder()
+            .password(password)
+            .build(&snapshot_path)?;
+
+        let coin_type = self.coin_type.load(Ordering::Relaxed);
+        if let Some(coin_type_bytes) = new_stronghold.get(COIN_TYPE_KEY.as_bytes()).await? {
+            let snapshot_coin_type = u32::from_le_bytes(
+                coin_type_bytes
+                    .try_into()
+                    .map_err(|_| crate::wallet::Error::Backup("invalid coin_type"))?,
+            );
+            if snapshot_coin_type != coin_type {
+                return Err(crate::wallet::Error::InvalidCoinType {
+                    new_coin_type: snapshot_coin_type,
+                    existing_coin_type: coin_type,
+                });
+            }
+        }
+
+        // Compare the first address of the first account with one generated from the new snapshot, so the accounts
+        // stay usable. Watch-only accounts weren't created from the seed and accounts without an address have nothing
+        // to compare, so they're skipped.
+        let accounts = self.accounts.read().await;
+        let mut accounts_details = Vec::with_capacity(accounts.len());
+        for account in accounts.iter() {
+            accounts_details.push(account.details().await);
+        }
+        if let Some((account_details, first_public_address)) = accounts_details
+            .iter()
+            .filter(|account_details| !account_details.watch_only())
+            .find_map(|account_details| {
+                account_details
+                    .public_addresses()
+                    .first()
+                    .map(|address| (account_details, address.address.inner))
+            })
+        {
+            let generated_address = new_stronghold
+                .generate_addresses(*account_details.coin_type(), *account_details.index(), 0..1, None)
+                .await?[0];
+            if generated_address != first_public_address {
+                return Err(crate::wallet::Error::InvalidMnemonic(
+                    "stronghold snapshot uses another seed".to_string(),
+                ));
+            }
+        }
+        drop(accounts_details);
+        drop(accounts);
+
+        new_stronghold.set_timeout(timeout).await;
+        *self.secret_manager.write().await = SecretManager::Stronghold(new_stronghold);
+
+        #[cfg(feature = "storage")]
+        {
+            self.storage_manager
+                .read()
+                .await
+                .save_wallet_data(&WalletBuilder::from_wallet(self).await)
+                .await?;
+        }
+
+        Ok(())
+    }
+
     /// Sets the Stronghold password clear interval
     pub async fn set_stronghold_password_clear_interval(&self, timeout: Option<Duration>) -> crate::wallet::Result<()> {
         if let SecretManager::Stronghold(stronghold) = &mut *self.secret_manager.write().await {
@@ -35,6 +126,16 @@ impl Wallet {
         Ok(())
     }
 
+    /// Gets the time left until the Stronghold password is cleared from memory. Returns `None` if no password is set
+    /// or no clear interval is configured.
+    pub async fn get_stronghold_password_clear_countdown(&self) -> crate::wallet::Result<Option<Duration>> {
+        log::debug!("[get_stronghold_password_clear_countdown]");
+        match &*self.secret_manager.read().await {
+            SecretManager::Stronghold(stronghold) => Ok(stronghold.get_key_clear_countdown().await),
+            _ => Err(crate::client::Error::SecretManagerMismatch.into()),
+        }
+    }
+
     /// Stores a mnemonic into the Stronghold vault
     pub async fn store_mnemonic(&self, mnemonic: String) -> crate::wallet::Result<()> {
         if let SecretManager::Stronghold(stronghold) = &mut *self.secret_manager.write().await {
@@ -6,6 +6,7 @@ pub(crate) mod stronghold_snapshot;
 use std::{fs, path::PathBuf, sync::atomic::Ordering};
 
 use futures::{future::try_join_all, FutureExt};
+use serde::{Deserialize, Serialize};
 use zeroize::Zeroize;
 
 use self::stronghold_snapshot::{read_data_from_stronghold_snapshot, store_data_to_stronghold};
@@ -13,14 +14,71 @@ use self::stronghold_snapshot::{read_data_from_stronghold_snapshot, store_data_t
 use crate::wallet::WalletBuilder;
 use crate::{
     client::secret::{stronghold::StrongholdSecretManager, SecretManager, SecretManagerDto},
-    wallet::{Account, Wallet},
+    wallet::{account::types::AccountIdentifier, Account, Wallet},
 };
 
+/// The contents of a Stronghold backup, read without restoring it.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupContents {
+    /// The coin type of the backed up wallet, if stored.
+    pub coin_type: Option<u32>,
+    /// The number of backed up accounts.
+    pub accounts: usize,
+    /// Whether a mnemonic is stored in the backup.
+    pub mnemonic_stored: bool,
+}
+
 impl Wallet {
     /// Backup the wallet data in a Stronghold file
     /// stronghold_password must be the current one when Stronghold is used as SecretManager.
-    pub async fn backup(&self, backup_path: PathBuf, mut stronghold_password: String) -> crate::wallet::Result<()> {
+    pub async fn backup(&self, backup_path: PathBuf, stronghold_password: String) -> crate::wallet::Result<()> {
         log::debug!("[backup] creating a stronghold backup");
+        let accounts = self.get_accounts().await?;
+
+        self.backup_inner(backup_path, stronghold_password, &accounts).await
+    }
+
+    /// Backup the wallet data in a Stronghold file, but only with the provided accounts.
+    /// The secret manager data is included, so the backup can be restored like a full one.
+    /// stronghold_password must be the current one when Stronghold is used as SecretManager.
+    pub async fn backup_accounts<I: Into<AccountIdentifier> + Send>(
+        &self,
+        backup_path: PathBuf,
+        mut stronghold_password: String,
+        account_ids: Vec<I>,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("[backup_accounts] creating a stronghold backup");
+
+        if account_ids.is_empty() {
+            stronghold_password.zeroize();
+            return Err(crate::wallet::Error::Backup("no accounts to backup provided"));
+        }
+
+        let mut accounts = Vec::with_capacity(account_ids.len());
+        for account_id in account_ids {
+            match self.get_account(account_id).await {
+                Ok(account) => accounts.push((*account.details().await.index(), account)),
+                Err(e) => {
+                    stronghold_password.zeroize();
+                    return Err(e);
+                }
+            }
+        }
+        // Keep the accounts ordered by index and without duplicates, like in the wallet
+        accounts.sort_by_key(|(index, _)| *index);
+        accounts.dedup_by_key(|(index, _)| *index);
+        let accounts = accounts.into_iter().map(|(_, account)| account).collect::<Vec<_>>();
+
+        self.backup_inner(backup_path, stronghold_password, &accounts).await
+    }
+
+    async fn backup_inner(
+        &self,
+        backup_path: PathBuf,
+        mut stronghold_password: String,
+        accounts: &[Account],
+    ) -> crate::wallet::Result<()> {
         let secret_manager = self.secret_manager.read().await;
 
         let secret_manager_dto = SecretManagerDto::from(&*secret_manager);
@@ -30,7 +88,7 @@ impl Wallet {
             SecretManager::Stronghold(stronghold) => {
                 stronghold.set_password(&stronghold_password).await?;
 
-                store_data_to_stronghold(self, stronghold, secret_manager_dto).await?;
+                store_data_to_stronghold(self, stronghold, secret_manager_dto, accounts).await?;
 
                 // Write snapshot to backup path
                 stronghold.write_stronghold_snapshot(Some(&backup_path)).await?;
@@ -42,7 +100,7 @@ impl Wallet {
                     .password(&stronghold_password)
                     .build(backup_path)?;
 
-                store_data_to_stronghold(self, &backup_stronghold, secret_manager_dto).await?;
+                store_data_to_stronghold(self, &backup_stronghold, secret_manager_dto, accounts).await?;
 
                 // Write snapshot to backup path
                 backup_stronghold.write_stronghold_snapshot(None).await?;
@@ -54,6 +112,36 @@ impl Wallet {
         Ok(())
     }
 
+    /// Read the contents of a Stronghold backup without restoring it, so it can be validated before calling
+    /// [`Wallet::restore_backup()`]. Neither the wallet nor any Stronghold file is modified.
+    pub async fn inspect_backup(
+        &self,
+        backup_path: PathBuf,
+        mut stronghold_password: String,
+    ) -> crate::wallet::Result<BackupContents> {
+        log::debug!("[inspect_backup] loading stronghold backup");
+
+        if !backup_path.is_file() {
+            stronghold_password.zeroize();
+            return Err(crate::wallet::Error::Backup("backup path doesn't exist"));
+        }
+
+        // The backup is loaded into a separate Stronghold, which is only kept in memory and never written back
+        let backup_stronghold = StrongholdSecretManager::builder()
+            .password(&stronghold_password)
+            .build(backup_path);
+        stronghold_password.zeroize();
+        let backup_stronghold = backup_stronghold?;
+
+        let (_, coin_type, _, accounts) = read_data_from_stronghold_snapshot(&backup_stronghold).await?;
+
+        Ok(BackupContents {
+            coin_type,
+            accounts: accounts.map_or(0, |accounts| accounts.len()),
+            mnemonic_stored: backup_stronghold.is_mnemonic_stored().await?,
+        })
+    }
+
     /// Restore a backup from a Stronghold file
     /// Replaces client_options, coin_type, secret_manager and accounts. Returns an error if accounts were already
     /// created If Stronghold is used as secret_manager, the existing Stronghold file will be overwritten. If a
@@ -6,7 +6,7 @@ use std::sync::atomic::Ordering;
 use crate::{
     client::{secret::SecretManagerDto, storage::StorageProvider, stronghold::StrongholdAdapter},
     wallet::{
-        account::AccountDetails,
+        account::{Account, AccountDetails},
         migration::{latest_migration_version, migrate_backup, MIGRATION_VERSION_KEY},
         ClientOptions, Wallet,
     },
@@ -21,6 +21,7 @@ pub(crate) async fn store_data_to_stronghold(
     wallet: &Wallet,
     stronghold: &StrongholdAdapter,
     secret_manager_dto: SecretManagerDto,
+    accounts: &[Account],
 ) -> crate::wallet::Result<()> {
     // Set migration version
     stronghold
@@ -55,7 +56,7 @@ pub(crate) async fn store_data_to_stronghold(
     }
 
     let mut serialized_accounts = Vec::new();
-    for account in wallet.accounts.read().await.iter() {
+    for account in accounts {
         serialized_accounts.push(serde_json::to_string(&*account.details().await)?);
     }
 
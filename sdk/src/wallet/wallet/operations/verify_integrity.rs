@@ -4,20 +4,24 @@
 use crate::wallet::Wallet;
 
 impl Wallet {
-    /// Checks if there is no missing account for example indexes [0, 1, 3] should panic (for now, later return error,
-    /// automatically fix?) Also checks for each account if there is a gap in an address list and no address is
-    /// duplicated
+    /// Checks if the accounts are ordered by their indexes without duplicates, for example indexes [0, 2, 1] should
+    /// panic (for now, later return error, automatically fix?). Gaps are allowed, because accounts can be removed.
+    /// Also checks for each account if there is a gap in an address list and no address is duplicated
     pub async fn verify_integrity(&self) -> crate::wallet::Result<()> {
         log::debug!("[verify_integrity]");
 
         let accounts = self.accounts.read().await;
 
-        // check that no account is missing and they're ordered
+        // check that accounts are ordered and not duplicated
         // check that no address is missing and they're ordered
-        for (account_index, account) in accounts.iter().enumerate() {
-            assert_eq!(accounts[account_index].details().await.index(), &(account_index as u32));
-
+        let mut previous_account_index = None;
+        for account in accounts.iter() {
             let account = account.details().await;
+            if let Some(previous_account_index) = previous_account_index {
+                assert!(account.index() > &previous_account_index);
+            }
+            previous_account_index = Some(*account.index());
+
             let public_addresses = account.public_addresses();
             for (index, public_address) in public_addresses.iter().enumerate() {
                 assert_eq!(public_address.key_index, index as u32);
@@ -0,0 +1,50 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::client::{AmountUnit, Client, Result};
+
+#[test]
+fn convert_amount() -> Result<()> {
+    assert_eq!(
+        Client::convert_amount("1500000", AmountUnit::Glow, AmountUnit::Smr)?,
+        "1.5"
+    );
+    assert_eq!(
+        Client::convert_amount("1.5", AmountUnit::Smr, AmountUnit::Glow)?,
+        "1500000"
+    );
+    assert_eq!(
+        Client::convert_amount("1.000000", AmountUnit::Smr, AmountUnit::Glow)?,
+        "1000000"
+    );
+    assert_eq!(Client::convert_amount("0.000001", AmountUnit::Mi, AmountUnit::I)?, "1");
+    assert_eq!(
+        Client::convert_amount("1", AmountUnit::I, AmountUnit::Pi)?,
+        "0.000000000000001"
+    );
+    assert_eq!(
+        Client::convert_amount("2.5", AmountUnit::Gi, AmountUnit::Ki)?,
+        "2500000"
+    );
+    // Larger than an f64 can represent exactly
+    assert_eq!(
+        Client::convert_amount("1813620509061365", AmountUnit::Glow, AmountUnit::Smr)?,
+        "1813620509.061365"
+    );
+    assert_eq!(
+        Client::convert_amount("1813620509.061365", AmountUnit::Smr, AmountUnit::Glow)?,
+        "1813620509061365"
+    );
+
+    // Fractions of the smallest unit
+    assert!(Client::convert_amount("0.5", AmountUnit::Glow, AmountUnit::Smr).is_err());
+    assert!(Client::convert_amount("0.0000001", AmountUnit::Smr, AmountUnit::Glow).is_err());
+    // Malformed amounts
+    for amount in ["", ".5", "1.", "-1", "+1", "1e6", "1,5", "1.2.3", " 1"] {
+        assert!(Client::convert_amount(amount, AmountUnit::Smr, AmountUnit::Glow).is_err());
+    }
+    // Overflow
+    assert!(Client::convert_amount(&"9".repeat(40), AmountUnit::Smr, AmountUnit::Glow).is_err());
+
+    Ok(())
+}
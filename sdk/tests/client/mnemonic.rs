@@ -1,7 +1,7 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::client::{Client, Result};
+use iota_sdk::client::{verify_mnemonic, Client, Result};
 
 #[tokio::test]
 async fn mnemonic() -> Result<()> {
@@ -17,5 +17,9 @@ async fn mnemonic() -> Result<()> {
         Client::mnemonic_to_hex_seed(mnemonic).unwrap(),
         Client::mnemonic_to_hex_seed(mnemonic_with_spaces).unwrap()
     );
+    // the mnemonic itself needs to be trimmed and lowercase
+    assert!(verify_mnemonic(mnemonic_with_spaces).is_err());
+    assert!(verify_mnemonic(&mnemonic.to_uppercase()).is_err());
+    assert!(verify_mnemonic(mnemonic).is_ok());
     Ok(())
 }
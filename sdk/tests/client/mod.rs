@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod addresses;
+mod amount;
 mod client_builder;
 mod common;
 mod error;
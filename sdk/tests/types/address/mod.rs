@@ -14,5 +14,12 @@ const ED25519_ADDRESS_INVALID: &str = "0x52fdfc072182654f163f5f0f9a621d729566c74
 fn invalid_bech32() {
     let address = Address::try_from_bech32(ED25519_ADDRESS_INVALID);
 
-    assert!(matches!(address, Err(Error::InvalidAddress)));
+    assert!(matches!(address, Err(Error::InvalidBech32(_))));
+}
+
+#[test]
+fn invalid_bech32_address_kind() {
+    let address = Address::try_from_bech32("rms1q9llaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxc5tspn");
+
+    assert!(matches!(address, Err(Error::InvalidAddressKind(1))));
 }
@@ -1,17 +1,21 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::Result;
-#[cfg(feature = "stronghold")]
-use {
-    iota_sdk::client::{
+use iota_sdk::{
+    client::{
         constants::SHIMMER_COIN_TYPE,
-        secret::{stronghold::StrongholdSecretManager, SecretManager},
+        secret::{mnemonic::MnemonicSecretManager, GenerateAddressOptions, SecretManage},
     },
+    types::block::{address::Bech32Address, payload::transaction::TransactionId},
+    wallet::{Error, Result, WalletDataJson},
+};
+#[cfg(feature = "stronghold")]
+use {
+    iota_sdk::client::secret::{stronghold::StrongholdSecretManager, SecretManager},
     iota_sdk::wallet::{ClientOptions, Wallet},
 };
 
-use crate::wallet::common::{make_wallet, setup, tear_down};
+use crate::wallet::common::{make_wallet, setup, tear_down, DEFAULT_MNEMONIC};
 
 #[tokio::test]
 async fn account_ordering() -> Result<()> {
@@ -103,6 +107,198 @@ async fn remove_latest_account() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn remove_account() -> Result<()> {
+    let storage_path = "test-storage/remove_account";
+    setup(storage_path)?;
+
+    {
+        let wallet = make_wallet(storage_path, None, None).await?;
+
+        for _ in 0..3 {
+            let _account = wallet.create_account().finish().await?;
+        }
+
+        // Remove the account in the middle, the other indexes don't change
+        wallet.remove_account(1, false).await?;
+        let mut account_indexes = Vec::new();
+        for account in wallet.get_accounts().await? {
+            account_indexes.push(*account.details().await.index());
+        }
+        assert_eq!(account_indexes, vec![0, 2]);
+        assert!(wallet.get_account(1).await.is_err());
+
+        // A new account gets the index after the highest one, so no index is reused
+        let new_account = wallet.create_account().finish().await?;
+        assert_eq!(*new_account.details().await.index(), 3);
+
+        #[cfg(debug_assertions)]
+        wallet.verify_integrity().await?;
+    }
+
+    // Restore dropped `Wallet` from above.
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let mut account_indexes = Vec::new();
+    for account in wallet.get_accounts().await? {
+        account_indexes.push(*account.details().await.index());
+    }
+    assert_eq!(account_indexes, vec![0, 2, 3]);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn set_coin_type() -> Result<()> {
+    let storage_path = "test-storage/set_coin_type";
+    setup(storage_path)?;
+
+    {
+        let wallet = make_wallet(storage_path, None, None).await?;
+        let account = wallet.create_account().finish().await?;
+        let _ = account.generate_addresses(2, None).await?;
+        let shimmer_address = account.addresses().await?[0].address().clone();
+
+        wallet
+            .set_coin_type(iota_sdk::client::constants::IOTA_COIN_TYPE, false)
+            .await?;
+
+        // Only the first address is generated again, with the new coin type
+        let addresses = account.addresses().await?;
+        assert_eq!(addresses.len(), 1);
+        assert_ne!(addresses[0].address(), &shimmer_address);
+        assert_eq!(
+            *account.details().await.coin_type(),
+            iota_sdk::client::constants::IOTA_COIN_TYPE
+        );
+    }
+
+    // Restore dropped `Wallet` from above, the new coin type is stored.
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.get_account(0).await?;
+    assert_eq!(
+        *account.details().await.coin_type(),
+        iota_sdk::client::constants::IOTA_COIN_TYPE
+    );
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn set_coin_type_with_watch_only_account() -> Result<()> {
+    let storage_path = "test-storage/set_coin_type_with_watch_only_account";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let address = Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")?;
+    wallet
+        .create_account()
+        .with_watch_only_addresses(vec![address])
+        .finish()
+        .await?;
+
+    // Watch-only accounts can't be migrated to another coin type, also not with `force`
+    for force in [false, true] {
+        assert!(matches!(
+            wallet
+                .set_coin_type(iota_sdk::client::constants::IOTA_COIN_TYPE, force)
+                .await,
+            Err(Error::WatchOnlyAccount(0))
+        ));
+    }
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn import_watch_only_accounts() -> Result<()> {
+    let storage_path = "test-storage/import_watch_only_accounts";
+    setup(storage_path)?;
+
+    let exporting_storage_path = "test-storage/import_watch_only_accounts_export";
+    setup(exporting_storage_path)?;
+    let exporting_wallet = make_wallet(exporting_storage_path, None, None).await?;
+    let exported_account = exporting_wallet
+        .create_account()
+        .with_alias("watched".to_string())
+        .finish()
+        .await?;
+    let data = WalletDataJson {
+        accounts: vec![exported_account.details().await.clone()],
+    };
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let _ = wallet.create_account().finish().await?;
+    let imported_accounts = wallet.import_accounts(data.clone()).await?;
+    assert_eq!(imported_accounts.len(), 1);
+
+    let imported_account = &imported_accounts[0];
+    assert_eq!(*imported_account.details().await.index(), 1);
+    assert!(*imported_account.details().await.watch_only());
+    assert_eq!(imported_account.addresses().await?, exported_account.addresses().await?);
+
+    // Watch-only accounts can't sign or generate addresses
+    assert!(matches!(
+        imported_account.sign_message(0, false, b"challenge").await,
+        Err(Error::WatchOnlyAccount(1))
+    ));
+    assert!(matches!(
+        imported_account.generate_addresses(1, None).await,
+        Err(Error::WatchOnlyAccount(1))
+    ));
+
+    // The alias is already used now
+    assert!(matches!(
+        wallet.import_accounts(data).await,
+        Err(Error::AccountAliasAlreadyExists(_))
+    ));
+
+    // Accounts can still be created from the secret manager of the wallet
+    let account = wallet.create_account().finish().await?;
+    assert_eq!(*account.details().await.index(), 2);
+    assert!(!*account.details().await.watch_only());
+
+    tear_down(exporting_storage_path)?;
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn create_watch_only_account() -> Result<()> {
+    let storage_path = "test-storage/create_watch_only_account";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let address = Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")?;
+    let account = wallet
+        .create_account()
+        .with_alias("watched".to_string())
+        .with_watch_only_addresses(vec![address.clone()])
+        .finish()
+        .await?;
+
+    assert!(*account.details().await.watch_only());
+    assert_eq!(account.addresses().await?[0].address(), &address);
+    let _ = account.sync(None).await?;
+    assert!(matches!(
+        account.sign_message(0, false, b"challenge").await,
+        Err(Error::WatchOnlyAccount(0))
+    ));
+
+    // At least one address is required
+    assert!(matches!(
+        wallet
+            .create_account()
+            .with_watch_only_addresses(Vec::new())
+            .finish()
+            .await,
+        Err(Error::WatchOnlyAccount(1))
+    ));
+
+    tear_down(storage_path)
+}
+
 #[tokio::test]
 async fn account_alias_already_exists() -> Result<()> {
     let storage_path = "test-storage/account_alias_already_exists";
@@ -183,6 +379,52 @@ async fn account_first_address_exists() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[tokio::test]
+async fn account_unused_addresses() -> Result<()> {
+    let storage_path = "test-storage/account_unused_addresses";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+    account.generate_addresses(2, None).await?;
+
+    // Without any outputs or transactions all addresses are unused
+    assert_eq!(account.unused_addresses().await?, account.addresses().await?);
+
+    // The first unused address is returned, without generating a new one
+    let next_address = account.get_next_unused_address(None).await?;
+    assert_eq!(next_address, account.addresses().await?[0]);
+    assert_eq!(account.addresses().await?.len(), 3);
+
+    // Internal addresses are generated if there is none yet
+    let next_internal_address = account
+        .get_next_unused_address(Some(GenerateAddressOptions::internal()))
+        .await?;
+    assert!(*next_internal_address.internal());
+    assert_eq!(account.addresses().await?.len(), 4);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn account_sign_message() -> Result<()> {
+    let storage_path = "test-storage/account_sign_message";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let message = b"challenge";
+    let signature = account.sign_message(0, false, message).await?;
+
+    // The signature can be verified against the first public address of the account
+    let address = *account.addresses().await?[0].address().inner();
+    assert!(signature.is_valid(message, address.as_ed25519()).is_ok());
+    assert!(!signature.verify(b"other challenge")?);
+
+    tear_down(storage_path)
+}
+
 #[cfg(feature = "stronghold")]
 #[tokio::test]
 async fn account_creation_stronghold() -> Result<()> {
@@ -215,3 +457,73 @@ async fn account_creation_stronghold() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[tokio::test]
+async fn transaction_note_unknown_transaction() -> Result<()> {
+    let storage_path = "test-storage/transaction_note_unknown_transaction";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    // Notes can only be set for transactions stored in the account
+    let transaction_id = TransactionId::null();
+    assert!(matches!(
+        account.set_transaction_note(&transaction_id, "rent").await,
+        Err(Error::TransactionNotFound(id)) if id == transaction_id
+    ));
+    assert!(account.get_transaction_note(&transaction_id).await.is_none());
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn retry_transaction_zero_max_attempts() -> Result<()> {
+    let storage_path = "test-storage/retry_transaction_zero_max_attempts";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    assert!(matches!(
+        account
+            .retry_transaction_until_included(&TransactionId::null(), None, Some(0))
+            .await,
+        Err(Error::InvalidMaxAttempts)
+    ));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn is_address_mine_gap_limit() -> Result<()> {
+    let storage_path = "test-storage/is_address_mine_gap_limit";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, Some(DEFAULT_MNEMONIC), None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    // Public address with index 5 and internal address with index 3 weren't generated yet
+    let secret_manager = MnemonicSecretManager::try_from_mnemonic(DEFAULT_MNEMONIC)?;
+    let hrp = account.addresses().await?[0].address().hrp().to_string();
+    let public_address = Bech32Address::new(
+        hrp.clone(),
+        secret_manager
+            .generate_addresses(SHIMMER_COIN_TYPE, 0, 5..6, None)
+            .await?[0],
+    )?;
+    let internal_address = Bech32Address::new(
+        hrp,
+        secret_manager
+            .generate_addresses(SHIMMER_COIN_TYPE, 0, 3..4, Some(GenerateAddressOptions::internal()))
+            .await?[0],
+    )?;
+    assert_eq!(account.addresses().await?.len(), 1);
+
+    for address in [&public_address, &internal_address] {
+        assert!(account.is_address_mine(address, None).await?);
+        assert!(!account.is_address_mine(address, Some(2)).await?);
+    }
+
+    tear_down(storage_path)
+}
@@ -523,3 +523,142 @@ async fn backup_and_restore_bech32_hrp_mismatch() -> Result<()> {
     );
     tear_down(storage_path)
 }
+
+#[tokio::test]
+#[cfg(all(feature = "stronghold", feature = "storage"))]
+// Backup only some accounts and restore them with Stronghold
+async fn backup_and_restore_selected_accounts() -> Result<()> {
+    let storage_path = "test-storage/backup_and_restore_selected_accounts";
+    setup(storage_path)?;
+
+    let client_options = ClientOptions::new().with_node(NODE_LOCAL)?;
+
+    let stronghold_password = "some_hopefully_secure_password";
+
+    // Create directory if not existing, because stronghold panics otherwise
+    std::fs::create_dir_all(storage_path).ok();
+    let stronghold = StrongholdSecretManager::builder()
+        .password(stronghold_password)
+        .build("test-storage/backup_and_restore_selected_accounts/1.stronghold")?;
+
+    stronghold.store_mnemonic("inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak".to_string()).await.unwrap();
+
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Stronghold(stronghold))
+        .with_client_options(client_options.clone())
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path("test-storage/backup_and_restore_selected_accounts/1")
+        .finish()
+        .await?;
+
+    let _alice = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    let bob = wallet.create_account().with_alias("Bob".to_string()).finish().await?;
+
+    // An empty list of accounts isn't allowed
+    wallet
+        .backup_accounts(
+            PathBuf::from("test-storage/backup_and_restore_selected_accounts/backup.stronghold"),
+            stronghold_password.to_string(),
+            Vec::<u32>::new(),
+        )
+        .await
+        .unwrap_err();
+
+    wallet
+        .backup_accounts(
+            PathBuf::from("test-storage/backup_and_restore_selected_accounts/backup.stronghold"),
+            stronghold_password.to_string(),
+            vec!["Bob"],
+        )
+        .await?;
+
+    // restore from backup
+
+    let stronghold =
+        StrongholdSecretManager::builder().build("test-storage/backup_and_restore_selected_accounts/2.stronghold")?;
+
+    let restore_wallet = Wallet::builder()
+        .with_storage_path("test-storage/backup_and_restore_selected_accounts/2")
+        .with_secret_manager(SecretManager::Stronghold(stronghold))
+        .with_client_options(ClientOptions::new().with_node(NODE_OTHER)?)
+        .with_coin_type(IOTA_COIN_TYPE)
+        .finish()
+        .await?;
+
+    restore_wallet
+        .restore_backup(
+            PathBuf::from("test-storage/backup_and_restore_selected_accounts/backup.stronghold"),
+            stronghold_password.to_string(),
+            None,
+            None,
+        )
+        .await?;
+
+    // Only the selected account is restored
+    let restored_accounts = restore_wallet.get_accounts().await?;
+    assert_eq!(restored_accounts.len(), 1);
+    assert!(restore_wallet.get_account("Alice").await.is_err());
+    let recovered_bob = restore_wallet.get_account("Bob").await?;
+    assert_eq!(bob.addresses().await?, recovered_bob.addresses().await?);
+
+    // secret manager is the same
+    assert_eq!(
+        bob.generate_addresses(1, None).await?,
+        recovered_bob.generate_addresses(1, None).await?
+    );
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+#[cfg(all(feature = "stronghold", feature = "storage"))]
+// Inspect a backup without restoring it
+async fn inspect_backup() -> Result<()> {
+    let storage_path = "test-storage/inspect_backup";
+    setup(storage_path)?;
+
+    let stronghold_password = "some_hopefully_secure_password";
+
+    // Create directory if not existing, because stronghold panics otherwise
+    std::fs::create_dir_all(storage_path).ok();
+    let stronghold = StrongholdSecretManager::builder()
+        .password(stronghold_password)
+        .build("test-storage/inspect_backup/1.stronghold")?;
+
+    stronghold.store_mnemonic("inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak".to_string()).await.unwrap();
+
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Stronghold(stronghold))
+        .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path("test-storage/inspect_backup/1")
+        .finish()
+        .await?;
+
+    wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    wallet.create_account().with_alias("Bob".to_string()).finish().await?;
+
+    let backup_path = PathBuf::from("test-storage/inspect_backup/backup.stronghold");
+    wallet
+        .backup(backup_path.clone(), stronghold_password.to_string())
+        .await?;
+    let backup_bytes = std::fs::read(&backup_path)?;
+
+    // Wrong password fails
+    wallet
+        .inspect_backup(backup_path.clone(), "wrong password".to_string())
+        .await
+        .unwrap_err();
+
+    let contents = wallet
+        .inspect_backup(backup_path.clone(), stronghold_password.to_string())
+        .await?;
+    assert_eq!(contents.coin_type, Some(SHIMMER_COIN_TYPE));
+    assert_eq!(contents.accounts, 2);
+    assert!(contents.mnemonic_stored);
+
+    // Neither the backup nor the wallet got modified
+    assert_eq!(std::fs::read(&backup_path)?, backup_bytes);
+    assert_eq!(wallet.get_accounts().await?.len(), 2);
+
+    tear_down(storage_path)
+}
@@ -71,6 +71,7 @@ fn balance_add_assign() {
     let num_aliases1 = balance1.aliases().len();
     let num_foundries1 = balance1.foundries().len();
     let num_nfts1 = balance1.nfts().len();
+    let num_potentially_locked_outputs1 = balance1.potentially_locked_outputs().len();
 
     let balance2 = AccountBalance::rand_mock();
     let total2 = balance2.base_coin().total();
@@ -87,6 +88,7 @@ fn balance_add_assign() {
     let num_aliases2 = balance2.aliases().len();
     let num_foundries2 = balance2.foundries().len();
     let num_nfts2 = balance2.nfts().len();
+    let num_potentially_locked_outputs2 = balance2.potentially_locked_outputs().len();
 
     balance1 += balance2;
 
@@ -106,6 +108,10 @@ fn balance_add_assign() {
     assert_eq!(balance1.aliases().len(), num_aliases1 + num_aliases2);
     assert_eq!(balance1.foundries().len(), num_foundries1 + num_foundries2);
     assert_eq!(balance1.nfts().len(), num_nfts1 + num_nfts2);
+    assert_eq!(
+        balance1.potentially_locked_outputs().len(),
+        num_potentially_locked_outputs1 + num_potentially_locked_outputs2
+    );
 
     let mut expected = std::collections::HashMap::new();
     for nt in native_tokens1.iter().chain(native_tokens2.iter()) {
@@ -136,6 +142,7 @@ async fn balance_expiration() -> Result<()> {
     let account_2 = wallet.create_account().finish().await?;
 
     let seconds_until_expired = 20;
+    let expiration_time = account_0.client().get_time_checked().await? + seconds_until_expired;
     let token_supply = account_0.client().get_token_supply().await?;
     let outputs = vec![
         BasicOutputBuilder::new_with_amount(1_000_000)
@@ -147,7 +154,7 @@ async fn balance_expiration() -> Result<()> {
                 UnlockCondition::Expiration(ExpirationUnlockCondition::new(
                     *account_2.addresses().await?[0].address().as_ref(),
                     // Current time + 20s
-                    account_0.client().get_time_checked().await? + seconds_until_expired,
+                    expiration_time,
                 )?),
             ])
             .with_features(vec![SenderFeature::new(
@@ -182,6 +189,12 @@ async fn balance_expiration() -> Result<()> {
     assert_eq!(balance.base_coin().total(), 0);
     assert_eq!(balance.base_coin().available(), 0);
 
+    // Account 2 balance at the expiration time
+    let balance = account_2.balance_at_time(expiration_time).await?;
+    assert_eq!(balance.potentially_locked_outputs().len(), 0);
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+    assert_eq!(balance.base_coin().available(), 1_000_000);
+
     // Wait until expired
     tokio::time::sleep(std::time::Duration::from_secs(seconds_until_expired.into())).await;
 
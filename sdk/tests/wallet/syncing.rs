@@ -9,7 +9,10 @@ use iota_sdk::{
         },
         AliasId, AliasOutputBuilder, BasicOutputBuilder, NftId, NftOutputBuilder, UnlockCondition,
     },
-    wallet::{account::SyncOptions, Result},
+    wallet::{
+        account::{RetryOptions, SyncOptions},
+        Result,
+    },
 };
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
@@ -45,6 +48,132 @@ async fn updated_default_sync_options() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[tokio::test]
+#[cfg(feature = "rocksdb")]
+async fn updated_default_retry_options() -> Result<()> {
+    let storage_path = "test-storage/updated_default_retry_options";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    assert_eq!(RetryOptions::default(), account.default_retry_options().await);
+
+    let custom_options = RetryOptions {
+        interval: 10,
+        max_attempts: 10,
+    };
+    account.set_default_retry_options(custom_options).await?;
+    assert_eq!(custom_options, account.default_retry_options().await);
+
+    drop(account);
+    drop(wallet);
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.get_account(0).await?;
+
+    assert_eq!(custom_options, account.default_retry_options().await);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+#[cfg(feature = "rocksdb")]
+async fn updated_default_sync_options_for_all() -> Result<()> {
+    let storage_path = "test-storage/updated_default_sync_options_for_all";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account_0 = wallet.create_account().finish().await?;
+
+    let custom_options = SyncOptions {
+        address_start_index: 10,
+        ..Default::default()
+    };
+    wallet.set_default_sync_options(custom_options.clone()).await?;
+    assert_eq!(custom_options, account_0.default_sync_options().await);
+
+    // Accounts created afterwards also use the options
+    let account_1 = wallet.create_account().finish().await?;
+    assert_eq!(custom_options, account_1.default_sync_options().await);
+
+    drop(account_0);
+    drop(account_1);
+    drop(wallet);
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    assert_eq!(
+        custom_options,
+        wallet.get_account(0).await?.default_sync_options().await
+    );
+    assert_eq!(
+        custom_options,
+        wallet.get_account(1).await?.default_sync_options().await
+    );
+    let account_2 = wallet.create_account().finish().await?;
+    assert_eq!(custom_options, account_2.default_sync_options().await);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn abort_sync_without_syncing() -> Result<()> {
+    let storage_path = "test-storage/abort_sync_without_syncing";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    // Returns immediately if no account is syncing
+    wallet.abort_sync().await?;
+    // Accounts can be used normally afterwards
+    account.get_next_unused_address(None).await?;
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn abort_account_sync_without_syncing() -> Result<()> {
+    let storage_path = "test-storage/abort_account_sync_without_syncing";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    // Returns immediately if the account isn't syncing
+    account.abort_sync().await?;
+    // The account can be used normally afterwards
+    account.get_next_unused_address(None).await?;
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn background_syncing_status() -> Result<()> {
+    let storage_path = "test-storage/background_syncing_status";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let status = wallet.background_syncing_status().await;
+    assert!(!status.running);
+    assert_eq!(status.interval, None);
+    assert_eq!(status.last_sync_timestamp, None);
+
+    let interval = std::time::Duration::from_secs(60);
+    wallet.start_background_syncing(None, Some(interval)).await?;
+    let status = wallet.background_syncing_status().await;
+    assert!(status.running);
+    assert_eq!(status.interval, Some(interval));
+
+    wallet.stop_background_syncing().await?;
+    let status = wallet.background_syncing_status().await;
+    assert!(!status.running);
+    assert_eq!(status.interval, None);
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn sync_only_most_basic_outputs() -> Result<()> {
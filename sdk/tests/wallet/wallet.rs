@@ -245,3 +245,54 @@ async fn update_node_auth() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn add_and_remove_node() -> Result<()> {
+    let storage_path = "test-storage/add_and_remove_node";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, Some(NODE_OTHER)).await?;
+
+    // The last remaining node can't be removed
+    assert!(matches!(
+        wallet.remove_node(Url::parse(NODE_OTHER).unwrap()).await,
+        Err(iota_sdk::wallet::Error::LastNode(_))
+    ));
+
+    wallet.add_node(Url::parse(NODE_LOCAL).unwrap(), None, false).await?;
+    let client_options = wallet.client_options().await;
+    assert_eq!(client_options.node_manager_builder.nodes.len(), 2);
+
+    wallet.remove_node(Url::parse(NODE_OTHER).unwrap()).await?;
+    let client_options = wallet.client_options().await;
+    let node_dto = NodeDto::Node(Node::from(Url::parse(NODE_LOCAL).unwrap()));
+    assert_eq!(client_options.node_manager_builder.nodes.len(), 1);
+    assert!(client_options.node_manager_builder.nodes.contains(&node_dto));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn set_primary_node() -> Result<()> {
+    let storage_path = "test-storage/set_primary_node";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, Some(NODE_OTHER)).await?;
+
+    assert!(matches!(
+        wallet.set_primary_node(Url::parse(NODE_LOCAL).unwrap()).await,
+        Err(iota_sdk::wallet::Error::NodeNotFound(_))
+    ));
+
+    wallet.add_node(Url::parse(NODE_LOCAL).unwrap(), None, false).await?;
+    wallet.set_primary_node(Url::parse(NODE_LOCAL).unwrap()).await?;
+
+    let client_options = wallet.client_options().await;
+    let node_dto = NodeDto::Node(Node::from(Url::parse(NODE_LOCAL).unwrap()));
+    assert_eq!(client_options.node_manager_builder.primary_node, Some(node_dto));
+    assert_eq!(client_options.node_manager_builder.nodes.len(), 2);
+
+    tear_down(storage_path)
+}